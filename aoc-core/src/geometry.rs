@@ -0,0 +1,325 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::parse::{self, ParseError};
+
+/// Integer type usable as a coordinate of a [`Point`]
+pub trait Coord: Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> {
+    /// Type of the distances between coordinates, unsigned
+    type Distance: Copy + Ord + Add<Output = Self::Distance>;
+
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs_diff(self, other: Self) -> Self::Distance;
+
+    fn distance_as_f64(distance: Self::Distance) -> f64;
+}
+
+macro_rules! coord {
+    ($($t:ty => $d:ty),*) => {
+        $(
+            impl Coord for $t {
+                type Distance = $d;
+
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs_diff(self, other: Self) -> $d {
+                    <$t>::abs_diff(self, other)
+                }
+
+                fn distance_as_f64(distance: $d) -> f64 {
+                    distance as f64
+                }
+            }
+        )*
+    };
+}
+
+coord!(i32 => u32, i64 => u64, isize => usize, u32 => u32, u64 => u64, usize => usize);
+
+/// Point of the plane, or a vector between two points.
+///
+/// Grids and directions use screen coordinates: `x` grows to the right and
+/// `y` grows downwards.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Coord> Point<T> {
+    /// Sum of the distances along each axis
+    pub fn manhattan(self, other: Self) -> T::Distance {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Largest of the distances along each axis, i.e. the number of king
+    /// moves between the points
+    pub fn chebyshev(self, other: Self) -> T::Distance {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Length of the straight line between the points
+    pub fn euclidean(self, other: Self) -> f64 {
+        let x = T::distance_as_f64(self.x.abs_diff(other.x));
+        let y = T::distance_as_f64(self.y.abs_diff(other.y));
+        x.hypot(y)
+    }
+}
+
+impl<T: Coord + Neg<Output = T>> Point<T> {
+    /// Quarter turn around the origin, clockwise on screen
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Quarter turn around the origin, counterclockwise on screen
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    /// Vector with each coordinate replaced by its sign: -1, 0 or 1
+    pub fn signum(self) -> Self {
+        let signum = |n: T| match n.cmp(&T::ZERO) {
+            std::cmp::Ordering::Less => -T::ONE,
+            std::cmp::Ordering::Equal => T::ZERO,
+            std::cmp::Ordering::Greater => T::ONE,
+        };
+        Self::new(signum(self.x), signum(self.y))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+/// Scaling by a factor
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// Parses `x,y` or `x=<x>, y=<y>`
+impl<T: FromStr> FromStr for Point<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((x, y)) = s.split_once(',') else {
+            return Err(ParseError::new(
+                s,
+                s,
+                "a point like 'x,y' or 'x=<x>, y=<y>'",
+            ));
+        };
+
+        let (x, y) = if s.contains('=') {
+            let y = y.strip_prefix(' ').unwrap_or(y);
+            match (x.strip_prefix("x="), y.strip_prefix("y=")) {
+                (Some(x), Some(y)) => (x, y),
+                (None, _) => return Err(ParseError::new(s, x, "'x=<x>'")),
+                (_, None) => return Err(ParseError::new(s, y, "'y=<y>'")),
+            }
+        } else {
+            (x, y)
+        };
+
+        Ok(Self::new(
+            parse::token(s, x, "a coordinate")?,
+            parse::token(s, y, "a coordinate")?,
+        ))
+    }
+}
+
+/// One of the 4 orthogonal directions on screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from `Up`
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Vector of a step in this direction
+    pub fn delta<T: Coord + Neg<Output = T>>(self) -> Point<T> {
+        match self {
+            Direction::Up => Point::new(T::ZERO, -T::ONE),
+            Direction::Right => Point::new(T::ONE, T::ZERO),
+            Direction::Down => Point::new(T::ZERO, T::ONE),
+            Direction::Left => Point::new(-T::ONE, T::ZERO),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// One of the 8 orthogonal and diagonal directions on screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    UpLeft,
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+}
+
+impl Direction8 {
+    /// Clockwise from `UpLeft`
+    pub const ALL: [Direction8; 8] = [
+        Direction8::UpLeft,
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+    ];
+
+    /// Vector of a step in this direction
+    pub fn delta<T: Coord + Neg<Output = T>>(self) -> Point<T> {
+        let (x, y) = match self {
+            Direction8::UpLeft => (-T::ONE, -T::ONE),
+            Direction8::Up => (T::ZERO, -T::ONE),
+            Direction8::UpRight => (T::ONE, -T::ONE),
+            Direction8::Right => (T::ONE, T::ZERO),
+            Direction8::DownRight => (T::ONE, T::ONE),
+            Direction8::Down => (T::ZERO, T::ONE),
+            Direction8::DownLeft => (-T::ONE, T::ONE),
+            Direction8::Left => (-T::ONE, T::ZERO),
+        };
+        Point::new(x, y)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::Up,
+            Direction::Right => Direction8::Right,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+        }
+    }
+}
+
+#[test]
+fn test_distances() {
+    let (a, b) = (Point::new(8i32, 7), Point::new(2, 10));
+    assert_eq!(a.manhattan(b), 9);
+    assert_eq!(a.chebyshev(b), 6);
+    assert_eq!(Point::new(0u32, 0).euclidean(Point::new(3, 4)), 5.0);
+}
+
+#[test]
+fn test_ops() {
+    let mut p = Point::new(1, 2) + Point::new(3, -4) * 2;
+    assert_eq!(p, Point::new(7, -6));
+    p -= Point::new(7, 0);
+    assert_eq!(-p, Point::new(0, 6));
+    assert_eq!(Point::new(5, -3).signum(), Point::new(1, -1));
+}
+
+#[test]
+fn test_rotate() {
+    let up = Direction::Up.delta::<i32>();
+    assert_eq!(up.rotate_right(), Direction::Right.delta());
+    assert_eq!(up.rotate_left(), Direction::Left.delta());
+    assert_eq!(up.rotate_right().rotate_right(), -up);
+
+    assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    assert_eq!(Direction::Left.turn_right(), Direction::Up);
+    assert_eq!(Direction::Down.opposite(), Direction::Up);
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!("498,4".parse(), Ok(Point::new(498usize, 4)));
+    assert_eq!("x=-2, y=15".parse(), Ok(Point::new(-2, 15)));
+
+    let err = "x=3, y=a".parse::<Point<i32>>().unwrap_err();
+    assert_eq!((err.column, err.text.as_str()), (8, "a"));
+    assert!("3;4".parse::<Point<i32>>().is_err());
+    assert!("x=3, z=4".parse::<Point<i32>>().is_err());
+}
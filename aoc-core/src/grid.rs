@@ -0,0 +1,320 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    geometry::{Direction, Direction8, Point},
+    parse::ParseError,
+};
+
+/// Column and row of a cell, starting from the top left corner
+pub type Pos = Point<usize>;
+
+/// Rectangular grid of cells, stored row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid whose rows are the consecutive chunks of `width` `cells`, or
+    /// `None` if the last row would be incomplete
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Option<Self> {
+        match (width, cells.len()) {
+            (0, 0) => Some(Self {
+                width,
+                height: 0,
+                cells,
+            }),
+            (0, _) => None,
+            (_, len) if len % width != 0 => None,
+            (_, len) => Some(Self {
+                width,
+                height: len / width,
+                cells,
+            }),
+        }
+    }
+
+    /// Grid of `width` by `height` copies of `value`
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parse a map with one character per cell, mapped by `cell`.
+    ///
+    /// Every line is a row and must be as long as the first one. Characters
+    /// rejected by `cell` are reported as not being `expected`.
+    pub fn parse(
+        s: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        let width = s.lines().next().map_or(0, |l| l.chars().count());
+        if width == 0 {
+            return Err(ParseError::new(s, s, "a grid"));
+        }
+
+        let mut cells = Vec::with_capacity(s.len());
+        for line in s.lines() {
+            if line.chars().count() != width {
+                return Err(ParseError::new(
+                    s,
+                    line,
+                    format!("a row of {} cells", width),
+                ));
+            }
+
+            for (i, c) in line.char_indices() {
+                let Some(value) = cell(c) else {
+                    return Err(ParseError::new(s, &line[i..i + c.len_utf8()], expected));
+                };
+                cells.push(value);
+            }
+        }
+
+        Ok(Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Cells row by row
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Index in [`Grid::cells`] of the cell at `pos`, if it is in the grid
+    pub fn index_of(&self, pos: Pos) -> Option<usize> {
+        (pos.x < self.width && pos.y < self.height).then_some(pos.y * self.width + pos.x)
+    }
+
+    /// Position of the cell at `index` in [`Grid::cells`]
+    pub fn pos_of(&self, index: usize) -> Pos {
+        let width = self.width.max(1);
+        Point::new(index % width, index / width)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width.max(1);
+        (0..self.cells.len()).map(move |i| Point::new(i % width, i / width))
+    }
+
+    /// Every cell along with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Position of the first cell, row by row, matching `predicate`
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|i| self.pos_of(i))
+    }
+
+    /// Grid of the same size with `f` applied to every cell
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Position one step from `pos` by `delta`, if it is in the grid
+    pub fn step(&self, pos: Pos, delta: Point<isize>) -> Option<Pos> {
+        let pos = Point::new(
+            pos.x.checked_add_signed(delta.x)?,
+            pos.y.checked_add_signed(delta.y)?,
+        );
+        self.index_of(pos).map(|_| pos)
+    }
+
+    /// Orthogonal neighbours of `pos` in the grid
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |d| self.step(pos, d.delta()))
+    }
+
+    /// Orthogonal and diagonal neighbours of `pos` in the grid
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |d| self.step(pos, d.delta()))
+    }
+
+    /// Rows from the top
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `max(1)` keeps `chunks` from panicking on an empty grid
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// Cells of column `x`, from the top
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let cells = if x < self.width {
+            &self.cells[x..]
+        } else {
+            &[]
+        };
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    /// Positions reached by repeatedly stepping from `from` by `delta`,
+    /// until the edge of the grid. `from` itself is not included, and
+    /// `delta` must not be zero.
+    pub fn ray(&self, from: Pos, delta: Point<isize>) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(self.step(from, delta), move |&pos| self.step(pos, delta))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!(
+                "position {:?} out of a {}x{} grid",
+                pos, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("position {:?} out of a {}x{} grid", pos, width, height),
+        }
+    }
+}
+
+/// Renders every cell with its own `Display`, one row per line
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+fn digits() -> Grid<u32> {
+    Grid::parse("123\n456", |c| c.to_digit(10), "a digit").unwrap()
+}
+
+#[test]
+fn test_parse() {
+    let grid = digits();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid.get(Point::new(2, 1)), Some(&6));
+    assert_eq!(grid.get(Point::new(3, 0)), None);
+    assert_eq!(grid.get(Point::new(0, 2)), None);
+    assert_eq!(grid.to_string(), "123\n456");
+
+    let err = Grid::parse("12\n3x", |c| c.to_digit(10), "a digit").unwrap_err();
+    assert_eq!(
+        (err.line, err.column, err.expected.as_str()),
+        (2, 2, "a digit")
+    );
+    let err = Grid::parse("12\n345", |c| c.to_digit(10), "a digit").unwrap_err();
+    assert_eq!((err.line, err.expected.as_str()), (2, "a row of 2 cells"));
+    assert!(Grid::parse("", |c| c.to_digit(10), "a digit").is_err());
+}
+
+#[test]
+fn test_neighbours() {
+    let grid = digits();
+    assert_eq!(
+        grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>(),
+        vec![Point::new(1, 0), Point::new(0, 1)]
+    );
+    assert_eq!(grid.neighbours4(Point::new(1, 1)).count(), 3);
+    assert_eq!(
+        grid.neighbours8(Point::new(1, 0))
+            .map(|p| grid[p])
+            .collect::<Vec<_>>(),
+        vec![3, 6, 5, 4, 1]
+    );
+}
+
+#[test]
+fn test_lines() {
+    let grid = digits();
+    assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+    assert_eq!(grid.row(2), None);
+    assert_eq!(grid.column(1).collect::<Vec<_>>(), vec![&2, &5]);
+    assert_eq!(grid.column(3).count(), 0);
+    assert_eq!(
+        grid.ray(Point::new(2, 1), Direction::Left.delta())
+            .collect::<Vec<_>>(),
+        vec![Point::new(1, 1), Point::new(0, 1)]
+    );
+    assert_eq!(
+        grid.ray(Point::new(0, 0), Direction8::UpLeft.delta())
+            .count(),
+        0
+    );
+    assert_eq!(
+        grid.ray(Point::new(0, 0), Direction8::DownRight.delta())
+            .collect::<Vec<_>>(),
+        vec![Point::new(1, 1)]
+    );
+}
+
+#[test]
+fn test_from_vec() {
+    assert_eq!(
+        Grid::from_vec(2, vec![1, 2, 3, 4]).map(|g| g.height()),
+        Some(2)
+    );
+    assert_eq!(Grid::from_vec(3, vec![1, 2, 3, 4]), None);
+    assert_eq!(Grid::filled(2, 1, '.').map(|_| '#').to_string(), "##");
+
+    let empty = Grid::filled(0, 3, '.');
+    assert_eq!(empty.positions().count(), 0);
+    assert_eq!(empty.pos_of(0), Pos::new(0, 0));
+    assert_eq!(empty.get(Pos::new(0, 0)), None);
+}
@@ -0,0 +1,104 @@
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Directory searched for `dayNN.txt` when no input is given
+pub const INPUTS_DIR: &str = "inputs";
+
+/// Path of the input of `day` inside `dir`
+pub fn default_path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day{:02}.txt", day))
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// No input was given and the conventional file does not exist
+    Missing {
+        day: u8,
+        path: PathBuf,
+    },
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Missing { day, path } => write!(
+                f,
+                "no input for day {}: {} does not exist (pass a file path, or `-` to read stdin)",
+                day,
+                path.display()
+            ),
+            InputError::Io { path, source } => {
+                write!(f, "cannot read {}: {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Missing { .. } => None,
+            InputError::Io { source, .. } => Some(source),
+        }
+    }
+}
+
+/// Load the input of `day`.
+///
+/// `path` is read if given, or stdin if it is `-`. Otherwise the input is
+/// read from `dayNN.txt` inside `dir`.
+pub fn load(day: u8, path: Option<&Path>, dir: &Path) -> Result<String, InputError> {
+    match path {
+        Some(path) if path == Path::new("-") => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|source| InputError::Io {
+                    path: PathBuf::from("<stdin>"),
+                    source,
+                })?;
+            Ok(input)
+        }
+        Some(path) => read(path),
+        None => {
+            let path = default_path(dir, day);
+            if !path.exists() {
+                return Err(InputError::Missing { day, path });
+            }
+            read(&path)
+        }
+    }
+}
+
+fn read(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|source| InputError::Io {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+#[test]
+fn test_load_missing() {
+    let err = load(7, None, Path::new("does-not-exist")).unwrap_err();
+    assert!(matches!(err, InputError::Missing { day: 7, .. }));
+    assert_eq!(
+        err.to_string(),
+        "no input for day 7: does-not-exist/day07.txt does not exist (pass a file path, or `-` to read stdin)"
+    );
+}
+
+#[test]
+fn test_load_path() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+    assert!(load(1, Some(&path), Path::new(INPUTS_DIR))
+        .unwrap()
+        .contains("aoc-core"));
+}
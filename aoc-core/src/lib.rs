@@ -0,0 +1,230 @@
+use std::{
+    any, env,
+    fmt::Display,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use parse::{Mode, ParseError};
+
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod parse;
+
+/// A solver for one day of the advent calendar.
+///
+/// The puzzle input is parsed once into [`Solution::Input`] and then shared
+/// by both parts.
+pub trait Solution {
+    /// Day of the advent calendar, starting from 1
+    const DAY: u8;
+
+    /// Parsed puzzle input
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// Parse the raw input, handling malformed items according to `mode`
+    fn parse(&self, input: &str, mode: Mode) -> Result<Self::Input, ParseError>;
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer1, SolveError>;
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer2, SolveError>;
+
+    /// Strictly parse the raw input and solve the first part
+    fn problem1(&self, input: &str) -> Result<Self::Answer1, SolveError> {
+        self.part1(&self.parse(input, Mode::Strict)?)
+    }
+
+    /// Strictly parse the raw input and solve the second part
+    fn problem2(&self, input: &str) -> Result<Self::Answer2, SolveError> {
+        self.part2(&self.parse(input, Mode::Strict)?)
+    }
+}
+
+/// Error raised when a part cannot be solved
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    /// The input is well-formed but has no answer, e.g. a move from an empty
+    /// stack or a map without a path to the summit
+    Invalid(String),
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::Parse(err) => write!(f, "{}", err),
+            SolveError::Invalid(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SolveError::Parse(err) => Some(err),
+            SolveError::Invalid(_) => None,
+        }
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(err: ParseError) -> Self {
+        SolveError::Parse(err)
+    }
+}
+
+/// Solve both parts and print the answers
+pub fn run<S: Solution>(solution: &S, input: &str, mode: Mode) -> Result<(), SolveError> {
+    let input = solution.parse(input, mode)?;
+
+    print_answer(Part::One, solution.part1(&input)?);
+    print_answer(Part::Two, solution.part2(&input)?);
+    Ok(())
+}
+
+/// Print an answer, starting multi-line answers such as images on a new line
+fn print_answer(part: Part, answer: impl Display) {
+    let answer = answer.to_string();
+    if answer.contains('\n') {
+        println!("Problem {}:\n{}", part, answer);
+    } else {
+        println!("Problem {}: {}", part, answer);
+    }
+}
+
+/// Entry point of the binary of a single day.
+///
+/// The input is read from the path given as first argument (`-` for stdin).
+/// Without arguments the `embedded` input is used if there is one, otherwise
+/// `inputs/dayNN.txt`.
+pub fn main<S: Solution>(solution: &S, embedded: Option<&str>) -> ExitCode {
+    let path = env::args_os().nth(1).map(PathBuf::from);
+
+    let input = match (path, embedded) {
+        (None, Some(embedded)) => Ok(embedded.to_owned()),
+        (path, _) => input::load(S::DAY, path.as_deref(), Path::new(input::INPUTS_DIR)),
+    };
+
+    let result = match input {
+        Ok(input) => run(solution, &input, Mode::Lenient).map_err(|err| err.to_string()),
+        Err(err) => Err(err.to_string()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// One of the two puzzles of a day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+/// Answer of a single part, along with the time it took to compute it
+#[derive(Debug, Clone)]
+pub struct Answer {
+    pub part: Part,
+    /// Name of the type of the answer, e.g. `u32` or `Crt`
+    pub kind: &'static str,
+    pub value: Result<String, SolveError>,
+    pub elapsed: Duration,
+}
+
+/// Outcome of solving some parts of a day
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub day: u8,
+    /// Time spent parsing the input
+    pub parse: Duration,
+    pub answers: Vec<Answer>,
+}
+
+/// Object-safe view of a [`Solution`].
+///
+/// Every [`Solution`] implements it, so days with different input and
+/// answer types can be stored and driven together.
+pub trait DynSolution {
+    fn day(&self) -> u8;
+
+    /// Parse the input once and solve the requested parts.
+    ///
+    /// If the input cannot be parsed, every part is answered with the error.
+    fn solve(&self, input: &str, parts: &[Part], mode: Mode) -> Report;
+}
+
+impl<S: Solution> DynSolution for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn solve(&self, input: &str, parts: &[Part], mode: Mode) -> Report {
+        let start = Instant::now();
+        let input = self.parse(input, mode);
+        let parse = start.elapsed();
+
+        let answers = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let kind = match part {
+                    Part::One => short_type_name::<S::Answer1>(),
+                    Part::Two => short_type_name::<S::Answer2>(),
+                };
+                let value = match (&input, part) {
+                    (Err(err), _) => Err(SolveError::Parse(err.clone())),
+                    (Ok(input), Part::One) => self.part1(input).map(|a| a.to_string()),
+                    (Ok(input), Part::Two) => self.part2(input).map(|a| a.to_string()),
+                };
+
+                Answer {
+                    part,
+                    kind,
+                    value,
+                    elapsed: start.elapsed(),
+                }
+            })
+            .collect();
+
+        Report {
+            day: S::DAY,
+            parse,
+            answers,
+        }
+    }
+}
+
+/// Name of `T` without its module path
+fn short_type_name<T>() -> &'static str {
+    let name = any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+#[test]
+fn test_short_type_name() {
+    assert_eq!(short_type_name::<u32>(), "u32");
+    assert_eq!(short_type_name::<String>(), "String");
+    assert_eq!(short_type_name::<Part>(), "Part");
+}
@@ -0,0 +1,189 @@
+use std::{fmt::Display, str::FromStr};
+
+/// Error raised when the puzzle input does not have the expected format
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of the input, starting from 1
+    pub line: usize,
+    /// Column of the offending text in its line, starting from 1
+    pub column: usize,
+    /// Offending text
+    pub text: String,
+    /// What was expected instead of `text`
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Error about `token`, which must be a slice of `source`.
+    ///
+    /// Line and column are computed from the position of `token` in `source`.
+    pub fn new(source: &str, token: &str, expected: impl Into<String>) -> Self {
+        let (line, column) = position(source, token);
+
+        Self {
+            line,
+            column,
+            text: token.to_owned(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Move an error raised while parsing `part` to the coordinates of
+    /// `source`, which `part` is a slice of
+    #[must_use]
+    pub fn within(self, source: &str, part: &str) -> Self {
+        let (line, column) = position(source, part);
+
+        Self {
+            line: line + self.line - 1,
+            column: if self.line == 1 {
+                column + self.column - 1
+            } else {
+                self.column
+            },
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+
+        if self.text.is_empty() {
+            write!(f, "found nothing")
+        } else {
+            write!(f, "found '{}'", self.text)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Line and column of `token` in `source`, or the first column of the first
+/// line if `token` is not a slice of `source`
+fn position(source: &str, token: &str) -> (usize, usize) {
+    let before = (token.as_ptr() as usize)
+        .checked_sub(source.as_ptr() as usize)
+        .and_then(|offset| source.get(..offset))
+        .unwrap_or_default();
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+/// Parse `token`, a slice of `source`, reporting `expected` on failure
+pub fn token<T: FromStr>(source: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::new(source, token, expected))
+}
+
+/// How the items of the input that cannot be parsed are handled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Mode {
+    /// Skip them, printing a warning
+    #[default]
+    Lenient,
+    /// Fail on the first one
+    Strict,
+}
+
+/// Parse each of `parts`, which are slices of `input`, with `parse`.
+///
+/// Empty parts are ignored. Errors are reported in the coordinates of `input`.
+pub fn items<'a, T>(
+    input: &'a str,
+    parts: impl Iterator<Item = &'a str>,
+    mode: Mode,
+    mut parse: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    let mut items = vec![];
+
+    for part in parts.filter(|p| !p.trim().is_empty()) {
+        match parse(part) {
+            Ok(item) => items.push(item),
+            Err(err) => {
+                let err = err.within(input, part);
+                match mode {
+                    Mode::Lenient => eprintln!("warning: skipping invalid input at {}", err),
+                    Mode::Strict => return Err(err),
+                }
+            }
+        }
+    }
+
+    Ok(items)
+}
+
+/// Parse every line of `input`
+pub fn lines<T: FromStr<Err = ParseError>>(input: &str, mode: Mode) -> Result<Vec<T>, ParseError> {
+    items(input, input.lines(), mode, str::parse)
+}
+
+/// Parse every block of `input`, blocks being separated by an empty line
+pub fn blocks<T: FromStr<Err = ParseError>>(input: &str, mode: Mode) -> Result<Vec<T>, ParseError> {
+    items(input, input.split("\n\n"), mode, str::parse)
+}
+
+#[test]
+fn test_position() {
+    let source = "abc\nde fg\nh";
+    assert_eq!(ParseError::new(source, &source[0..1], "x").column, 1);
+    assert_eq!(
+        ParseError::new(source, &source[7..9], "x"),
+        ParseError {
+            line: 2,
+            column: 4,
+            text: "fg".to_owned(),
+            expected: "x".to_owned()
+        }
+    );
+    assert_eq!(ParseError::new(source, &source[11..], "x").line, 3);
+    assert_eq!(ParseError::new(source, "elsewhere", "x").line, 1);
+}
+
+#[test]
+fn test_within() {
+    let source = "1,2\n3,x";
+    let line = &source[4..];
+    let err = token::<u8>(line, &line[2..], "a number")
+        .unwrap_err()
+        .within(source, line);
+
+    assert_eq!((err.line, err.column), (2, 3));
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 3: expected a number, found 'x'"
+    );
+}
+
+#[test]
+fn test_lines() {
+    let input = "1\n2\nx\n4";
+    assert_eq!(
+        lines::<Num>(input, Mode::Lenient),
+        Ok(vec![Num(1), Num(2), Num(4)])
+    );
+    assert_eq!(lines::<Num>(input, Mode::Strict).unwrap_err().line, 3);
+}
+
+#[cfg(test)]
+#[derive(Debug, PartialEq)]
+struct Num(u8);
+
+#[cfg(test)]
+impl FromStr for Num {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        token(s, s, "a number").map(Num)
+    }
+}
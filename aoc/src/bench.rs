@@ -0,0 +1,302 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{parse::Mode, Part, Report};
+
+use crate::days::Entry;
+
+/// Step of a solution that is timed on its own
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    Parse,
+    Part(Part),
+}
+
+impl Phase {
+    const ALL: [Phase; 3] = [Phase::Parse, Phase::Part(Part::One), Phase::Part(Part::Two)];
+
+    fn name(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part(Part::One) => "part1",
+            Phase::Part(Part::Two) => "part2",
+        }
+    }
+
+    /// Duration of this phase in `report`, or the error that stopped it
+    fn elapsed(self, report: &Report) -> Result<Duration, String> {
+        match self {
+            Phase::Parse => Ok(report.parse),
+            Phase::Part(part) => {
+                let answer = report
+                    .answers
+                    .iter()
+                    .find(|a| a.part == part)
+                    .ok_or_else(|| format!("part {} was not solved", part))?;
+                answer
+                    .value
+                    .as_ref()
+                    .map(|_| answer.elapsed)
+                    .map_err(|err| err.to_string())
+            }
+        }
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+/// Summary of the samples of a phase
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Summarize `samples`, `None` if there are none
+    pub fn new(samples: &mut [Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        samples.sort_unstable();
+        Some(Self {
+            min: samples[0],
+            median: percentile(samples, 50),
+            p95: percentile(samples, 95),
+        })
+    }
+}
+
+/// Nearest-rank percentile of non-empty, sorted `samples`
+fn percentile(samples: &[Duration], p: usize) -> Duration {
+    let rank = (p * samples.len()).div_ceil(100).max(1);
+    samples[rank - 1]
+}
+
+/// Timing of one phase of a day
+pub struct Measure {
+    pub day: u8,
+    pub phase: Phase,
+    pub stats: Result<Stats, String>,
+}
+
+/// Solve `entry` on `input` once to warm up, then up to `iterations` times
+/// or until `max_time` has elapsed, timing each phase separately.
+pub fn bench(entry: &Entry, input: &str, iterations: usize, max_time: Duration) -> Vec<Measure> {
+    let day = entry.solution.day();
+    let warmup = entry.solution.solve(input, &Part::ALL, Mode::Strict);
+
+    let mut samples = BTreeMap::<Phase, Vec<Duration>>::new();
+    let start = Instant::now();
+    for _ in 0..iterations {
+        let report = entry.solution.solve(input, &Part::ALL, Mode::Strict);
+        for phase in Phase::ALL {
+            if let Ok(elapsed) = phase.elapsed(&report) {
+                samples.entry(phase).or_default().push(elapsed);
+            }
+        }
+
+        if start.elapsed() >= max_time {
+            break;
+        }
+    }
+
+    Phase::ALL
+        .into_iter()
+        .map(|phase| Measure {
+            day,
+            phase,
+            stats: match samples.get_mut(&phase).and_then(|s| Stats::new(s)) {
+                Some(stats) => Ok(stats),
+                None => Err(phase
+                    .elapsed(&warmup)
+                    .err()
+                    .unwrap_or_else(|| "no samples".to_owned())),
+            },
+        })
+        .collect()
+}
+
+/// Median time of every phase of every day, from an earlier run.
+///
+/// It is stored as TOML, in nanoseconds:
+///
+/// ```toml
+/// [day01]
+/// parse = 41523
+/// part1 = 270
+/// part2 = 541
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline(BTreeMap<u8, BTreeMap<Phase, Duration>>);
+
+impl Baseline {
+    /// Load the baseline at `path`, or an empty one if it does not exist
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let text = fs::read_to_string(path)
+            .map_err(|err| format!("cannot read {}: {}", path.display(), err))?;
+        text.parse()
+            .map_err(|err| format!("{}: {}", path.display(), err))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_string())
+            .map_err(|err| format!("cannot write {}: {}", path.display(), err))
+    }
+
+    pub fn get(&self, day: u8, phase: Phase) -> Option<Duration> {
+        self.0.get(&day)?.get(&phase).copied()
+    }
+
+    /// Record the median of every successful measure
+    pub fn update(&mut self, measures: &[Measure]) {
+        for m in measures {
+            if let Ok(stats) = &m.stats {
+                self.0
+                    .entry(m.day)
+                    .or_default()
+                    .insert(m.phase, stats.median);
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for Baseline {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let table = s.parse::<toml::Table>().map_err(|err| err.to_string())?;
+        let mut baseline = Baseline::default();
+
+        for (day_key, phases) in table {
+            let day = day_key
+                .strip_prefix("day")
+                .and_then(|n| n.parse::<u8>().ok())
+                .ok_or_else(|| format!("expected a table named dayNN, found '{}'", day_key))?;
+            let phases = phases
+                .as_table()
+                .ok_or_else(|| format!("{}: expected a table of phases", day_key))?;
+
+            for (phase_key, value) in phases {
+                let phase = Phase::ALL
+                    .into_iter()
+                    .find(|p| p.name() == phase_key)
+                    .ok_or_else(|| {
+                        format!(
+                            "{}: expected parse, part1 or part2, found '{}'",
+                            day_key, phase_key
+                        )
+                    })?;
+                let nanos = value
+                    .as_integer()
+                    .and_then(|n| u64::try_from(n).ok())
+                    .ok_or_else(|| {
+                        format!(
+                            "{}.{}: expected a number of nanoseconds",
+                            day_key, phase_key
+                        )
+                    })?;
+
+                baseline
+                    .0
+                    .entry(day)
+                    .or_default()
+                    .insert(phase, Duration::from_nanos(nanos));
+            }
+        }
+
+        Ok(baseline)
+    }
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (day, phases)) in self.0.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "[day{:02}]", day)?;
+            for (phase, median) in phases {
+                writeln!(f, "{} = {}", phase, median.as_nanos())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Relative change from `baseline` to `current`, in percent, or `None` if
+/// the baseline is zero and there is nothing to compare with
+pub fn change(baseline: Duration, current: Duration) -> Option<f64> {
+    if baseline.is_zero() {
+        return None;
+    }
+    Some((current.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0)
+}
+
+#[test]
+fn test_stats() {
+    let mut samples = (1..=20)
+        .rev()
+        .map(Duration::from_millis)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        Stats::new(&mut samples),
+        Some(Stats {
+            min: Duration::from_millis(1),
+            median: Duration::from_millis(10),
+            p95: Duration::from_millis(19),
+        })
+    );
+
+    let mut one = [Duration::from_millis(3)];
+    assert_eq!(Stats::new(&mut one).unwrap().p95, Duration::from_millis(3));
+    assert_eq!(Stats::new(&mut []), None);
+}
+
+#[test]
+fn test_change() {
+    let ms = Duration::from_millis;
+    assert_eq!(change(ms(10), ms(15)), Some(50.0));
+    assert_eq!(change(ms(10), ms(5)), Some(-50.0));
+    assert_eq!(change(Duration::ZERO, ms(5)), None);
+}
+
+#[test]
+fn test_baseline_roundtrip() {
+    let mut baseline = Baseline::default();
+    baseline.update(&[
+        Measure {
+            day: 1,
+            phase: Phase::Parse,
+            stats: Ok(Stats {
+                min: Duration::from_nanos(1),
+                median: Duration::from_nanos(2),
+                p95: Duration::from_nanos(3),
+            }),
+        },
+        Measure {
+            day: 1,
+            phase: Phase::Part(Part::Two),
+            stats: Err("no samples".to_owned()),
+        },
+    ]);
+
+    assert_eq!(baseline.to_string(), "[day01]\nparse = 2\n");
+    assert_eq!(baseline.to_string().parse(), Ok(baseline));
+    assert!("[day01]\npart3 = 2".parse::<Baseline>().is_err());
+}
@@ -0,0 +1,55 @@
+use aoc_core::DynSolution;
+
+/// A registered day: its solver and, with the `embed` feature, its input
+pub struct Entry {
+    pub solution: Box<dyn DynSolution>,
+    pub embedded: Option<&'static str>,
+}
+
+#[cfg(feature = "embed")]
+macro_rules! embedded {
+    ($krate:ident) => {
+        Some(include_str!(concat!(
+            "../../inputs/",
+            stringify!($krate),
+            ".txt"
+        )))
+    };
+}
+
+#[cfg(not(feature = "embed"))]
+macro_rules! embedded {
+    ($krate:ident) => {
+        None
+    };
+}
+
+macro_rules! entry {
+    ($krate:ident, $solution:expr) => {
+        Entry {
+            solution: Box::new($solution),
+            embedded: embedded!($krate),
+        }
+    };
+}
+
+/// Every day of the workspace, in calendar order
+pub fn all() -> Vec<Entry> {
+    vec![
+        entry!(day01, day01::Day01),
+        entry!(day02, day02::Day02),
+        entry!(day03, day03::Day03),
+        entry!(day04, day04::Day04),
+        entry!(day05, day05::Day05),
+        entry!(day06, day06::Day06),
+        entry!(day07, day07::Day07),
+        entry!(day08, day08::Day08),
+        entry!(day09, day09::Day09),
+        entry!(day10, day10::Day10),
+        entry!(day11, day11::Day11),
+        entry!(day12, day12::Day12),
+        entry!(day13, day13::Day13),
+        entry!(day14, day14::Day14),
+        entry!(day15, day15::Day15::default()),
+    ]
+}
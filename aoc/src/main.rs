@@ -0,0 +1,441 @@
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Duration,
+};
+
+use aoc_core::{input, parse::Mode, Part, Report};
+use clap::{Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+
+mod bench;
+mod days;
+mod scaffold;
+mod verify;
+
+#[derive(Parser)]
+#[command(about = "Run the Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List the available days
+    List,
+    /// Solve a single day, or every day with `all`
+    Run {
+        /// Day number, or `all`
+        #[arg(value_parser = parse_selection)]
+        day: Selection,
+        /// Solve only this part (1 or 2)
+        #[arg(long, value_parser = parse_part)]
+        part: Option<Part>,
+        /// Read the puzzle input from this file, or from stdin with `-`
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Directory searched for `dayNN.txt` when no input is given
+        #[arg(long, default_value = input::INPUTS_DIR)]
+        inputs: PathBuf,
+        /// Fail on malformed input instead of skipping it
+        #[arg(long)]
+        strict: bool,
+        /// Print a table, or one JSON object per line for each day and part
+        #[arg(long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+    },
+    /// Time parsing and each part of a single day, or every day with `all`
+    Bench {
+        /// Day number, or `all`
+        #[arg(value_parser = parse_selection, default_value = "all")]
+        day: Selection,
+        /// Maximum number of timed runs of each day
+        #[arg(long, default_value_t = 100)]
+        iterations: usize,
+        /// Stop timing a day after this many seconds, even if fewer runs were made
+        #[arg(long, default_value_t = 5)]
+        max_time: u64,
+        /// Directory searched for `dayNN.txt`
+        #[arg(long, default_value = input::INPUTS_DIR)]
+        inputs: PathBuf,
+        /// Compare the medians with the ones saved in this file
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Save the medians to this file, keeping the days that were not run
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// Flag phases whose median is slower than the baseline by more than
+        /// this percentage
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Check every day against the known answers
+    Verify {
+        /// File mapping each day, input and part to its expected answer
+        #[arg(long, default_value = verify::ANSWERS_FILE)]
+        answers: PathBuf,
+        /// Directory searched for `dayNN.txt`
+        #[arg(long, default_value = input::INPUTS_DIR)]
+        inputs: PathBuf,
+    },
+    /// Generate a new day from the template and register it in the workspace
+    New {
+        /// Day number
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Table,
+    Json,
+}
+
+#[derive(Clone, Copy)]
+enum Selection {
+    All,
+    Day(u8),
+}
+
+fn parse_selection(s: &str) -> Result<Selection, String> {
+    match s {
+        "all" => Ok(Selection::All),
+        _ => s
+            .parse::<u8>()
+            .map(Selection::Day)
+            .map_err(|_| format!("expected a day number or `all`, got '{}'", s)),
+    }
+}
+
+fn parse_part(s: &str) -> Result<Part, String> {
+    match s {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("expected part 1 or 2, got '{}'", s)),
+    }
+}
+
+fn main() -> ExitCode {
+    match Cli::parse().command {
+        Command::List => {
+            for entry in days::all() {
+                println!("day{:02}", entry.solution.day());
+            }
+            ExitCode::SUCCESS
+        }
+        Command::Run {
+            day,
+            part,
+            input,
+            inputs,
+            strict,
+            format,
+        } => {
+            let mode = if strict { Mode::Strict } else { Mode::Lenient };
+            match run(day, part, input, inputs, mode, format) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(err) => {
+                    eprintln!("error: {}", err);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Bench {
+            day,
+            iterations,
+            max_time,
+            inputs,
+            baseline,
+            save,
+            threshold,
+        } => {
+            let options = BenchOptions {
+                iterations,
+                max_time: Duration::from_secs(max_time),
+                baseline,
+                save,
+                threshold,
+            };
+            match run_bench(day, &inputs, &options) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(err) => {
+                    eprintln!("error: {}", err);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Verify { answers, inputs } => {
+            match verify::Answers::load(&answers).map(|a| run_verify(&a, &inputs)) {
+                Ok(true) => ExitCode::SUCCESS,
+                Ok(false) => ExitCode::FAILURE,
+                Err(err) => {
+                    eprintln!("error: {}", err);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::New { day } => match scaffold::new_day(Path::new("."), day) {
+            Ok(paths) => {
+                for path in paths {
+                    println!("wrote {}", path.display());
+                }
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                eprintln!("error: {}", err);
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
+
+fn run(
+    selection: Selection,
+    part: Option<Part>,
+    input: Option<PathBuf>,
+    inputs: PathBuf,
+    mode: Mode,
+    format: Format,
+) -> Result<(), String> {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    let entries = select(selection)?;
+
+    if input.is_some() && matches!(selection, Selection::All) {
+        return Err("--input can only be used with a single day".to_owned());
+    }
+
+    let reports = entries
+        .iter()
+        .map(|e| {
+            let input = load_input(e, input.as_deref(), &inputs)?;
+            Ok(e.solution.solve(&input, &parts, mode))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    match format {
+        Format::Table => print_table(&reports),
+        Format::Json => print_json(&reports)?,
+    }
+
+    let failed = reports
+        .iter()
+        .flat_map(|r| &r.answers)
+        .filter(|a| a.value.is_err())
+        .count();
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{} part(s) could not be solved", failed)),
+    }
+}
+
+/// The registered days matching `selection`
+fn select(selection: Selection) -> Result<Vec<days::Entry>, String> {
+    let entries = days::all()
+        .into_iter()
+        .filter(|e| match selection {
+            Selection::All => true,
+            Selection::Day(day) => e.solution.day() == day,
+        })
+        .collect::<Vec<_>>();
+
+    if entries.is_empty() {
+        return Err("no such day".to_owned());
+    }
+    Ok(entries)
+}
+
+/// Input of `entry`: the file at `path`, or the embedded one, or the one in
+/// `inputs`
+fn load_input(entry: &days::Entry, path: Option<&Path>, inputs: &Path) -> Result<String, String> {
+    match (path, entry.embedded) {
+        (None, Some(embedded)) => Ok(embedded.to_owned()),
+        (path, _) => input::load(entry.solution.day(), path, inputs).map_err(|err| err.to_string()),
+    }
+}
+
+fn print_table(reports: &[Report]) {
+    println!(
+        "{:>3}  {:>4}  {:<20}  {:>12}",
+        "Day", "Part", "Answer", "Time"
+    );
+
+    for report in reports {
+        for answer in &report.answers {
+            let value = match &answer.value {
+                Ok(value) => value.clone(),
+                Err(err) => format!("error: {}", err),
+            };
+            // Multi-line answers, e.g. images, start on the next line
+            let mut lines = value.lines();
+            let first = if value.contains('\n') {
+                ""
+            } else {
+                lines.next().unwrap_or_default()
+            };
+            println!(
+                "{:>3}  {:>4}  {:<20}  {:>12}",
+                report.day,
+                answer.part,
+                first,
+                format!("{:.2?}", answer.elapsed)
+            );
+            for line in lines {
+                println!("{:11}{}", "", line);
+            }
+        }
+    }
+
+    let total = reports
+        .iter()
+        .flat_map(|r| r.answers.iter().map(|a| a.elapsed).chain([r.parse]))
+        .sum::<Duration>();
+    println!("Total time: {:.2?}", total);
+}
+
+/// Answer of a part as printed by `--format json`
+#[derive(Serialize)]
+struct JsonAnswer<'a> {
+    day: u8,
+    part: u8,
+    #[serde(rename = "type")]
+    kind: &'a str,
+    answer: Option<&'a str>,
+    error: Option<String>,
+    parse_ns: u128,
+    time_ns: u128,
+}
+
+fn print_json(reports: &[Report]) -> Result<(), String> {
+    for report in reports {
+        for answer in &report.answers {
+            let json = JsonAnswer {
+                day: report.day,
+                part: match answer.part {
+                    Part::One => 1,
+                    Part::Two => 2,
+                },
+                kind: answer.kind,
+                answer: answer.value.as_deref().ok(),
+                error: answer.value.as_ref().err().map(|err| err.to_string()),
+                parse_ns: report.parse.as_nanos(),
+                time_ns: answer.elapsed.as_nanos(),
+            };
+            println!(
+                "{}",
+                serde_json::to_string(&json).map_err(|err| err.to_string())?
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Print the outcome of every check, returning whether none failed
+fn run_verify(answers: &verify::Answers, inputs: &Path) -> bool {
+    let checks = verify::verify(&days::all(), answers, inputs);
+
+    println!("{:>3}  {:>4}  {:<10}  Status", "Day", "Part", "Input");
+    for check in &checks {
+        println!(
+            "{:>3}  {:>4}  {:<10}  {}",
+            check.day, check.part, check.input, check.status
+        );
+    }
+
+    let count = |f: fn(&verify::Status) -> bool| checks.iter().filter(|c| f(&c.status)).count();
+    let failed = count(|s| matches!(s, verify::Status::Fail { .. }));
+    println!(
+        "{} passed, {} failed, {} missing",
+        count(|s| matches!(s, verify::Status::Pass)),
+        failed,
+        count(|s| matches!(s, verify::Status::Missing(_)))
+    );
+
+    failed == 0
+}
+
+struct BenchOptions {
+    iterations: usize,
+    max_time: Duration,
+    baseline: Option<PathBuf>,
+    save: Option<PathBuf>,
+    threshold: f64,
+}
+
+fn run_bench(selection: Selection, inputs: &Path, options: &BenchOptions) -> Result<(), String> {
+    let baseline = match &options.baseline {
+        Some(path) => Some(bench::Baseline::load(path)?),
+        None => None,
+    };
+
+    println!(
+        "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}  {:>9}",
+        "Day", "Phase", "Min", "Median", "p95", "Baseline"
+    );
+
+    let mut measures = vec![];
+    let mut regressions = 0;
+    for entry in select(selection)? {
+        let input = match load_input(&entry, None, inputs) {
+            Ok(input) => input,
+            Err(err) => {
+                println!("{:>3}  {:<5}  missing  {}", entry.solution.day(), "", err);
+                continue;
+            }
+        };
+        let day = bench::bench(&entry, &input, options.iterations, options.max_time);
+
+        for m in &day {
+            let stats = match &m.stats {
+                Ok(stats) => stats,
+                Err(err) => {
+                    println!("{:>3}  {:<5}  error: {}", m.day, m.phase, err);
+                    continue;
+                }
+            };
+
+            let change = baseline
+                .as_ref()
+                .and_then(|b| b.get(m.day, m.phase))
+                .and_then(|old| bench::change(old, stats.median));
+            let change = match change {
+                Some(change) if change > options.threshold => {
+                    regressions += 1;
+                    format!("{:>+8.1}%  REGRESSION", change)
+                }
+                Some(change) => format!("{:>+8.1}%", change),
+                None => String::new(),
+            };
+
+            println!(
+                "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}  {}",
+                m.day,
+                m.phase,
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.p95),
+                change
+            );
+        }
+        measures.extend(day);
+    }
+
+    if let Some(path) = &options.save {
+        let mut saved = bench::Baseline::load(path)?;
+        saved.update(&measures);
+        saved.save(path)?;
+    }
+
+    match regressions {
+        0 => Ok(()),
+        _ => Err(format!(
+            "{} phase(s) slower than the baseline by more than {}%",
+            regressions, options.threshold
+        )),
+    }
+}
@@ -0,0 +1,223 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_core::input;
+
+/// Directory holding the files a new day is generated from
+const TEMPLATE_DIR: &str = "template";
+
+/// Generate the crate of `day` from the template and register it in the
+/// workspace and in the runner.
+///
+/// Every file is computed before anything is written, so on error the
+/// workspace is left untouched. Returns the paths that were written.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let krate = format!("day{:02}", day);
+    let dir = root.join(&krate);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let template = root.join(TEMPLATE_DIR);
+    let mut files = vec![
+        (dir.join("Cargo.toml"), manifest(&krate)),
+        (
+            dir.join("src/lib.rs"),
+            render(&read(&template.join("lib.rs"))?, day),
+        ),
+        (
+            dir.join("src/main.rs"),
+            render(&read(&template.join("main.rs"))?, day),
+        ),
+        (
+            dir.join("src/testdata.txt"),
+            read(&template.join("testdata.txt"))?,
+        ),
+    ];
+
+    let input = input::default_path(&root.join(input::INPUTS_DIR), day);
+    if !input.exists() {
+        files.push((input, String::new()));
+    }
+
+    let workspace = root.join("Cargo.toml");
+    let runner = root.join("aoc/Cargo.toml");
+    let days = root.join("aoc/src/days.rs");
+    files.extend([
+        (
+            workspace.clone(),
+            insert_sorted(
+                &read(&workspace)?,
+                ("members = [", "]"),
+                "\"day",
+                &format!("    \"{}\",", krate),
+            )?,
+        ),
+        (
+            runner.clone(),
+            insert_sorted(
+                &read(&runner)?,
+                ("[dependencies]", "["),
+                "day",
+                &format!("{} = {{ path = \"../{}\" }}", krate, krate),
+            )?,
+        ),
+        (
+            days.clone(),
+            insert_sorted(
+                &read(&days)?,
+                ("pub fn all()", "]"),
+                "entry!(day",
+                &format!("        entry!({}, {}::Day{:02}),", krate, krate, day),
+            )?,
+        ),
+    ]);
+
+    for (path, contents) in &files {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|err| format!("cannot create {}: {}", parent.display(), err))?;
+        }
+        fs::write(path, contents)
+            .map_err(|err| format!("cannot write {}: {}", path.display(), err))?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("cannot read {}: {}", path.display(), err))
+}
+
+fn manifest(krate: &str) -> String {
+    format!(
+        r#"[package]
+name = "{krate}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[features]
+# Embed inputs/{krate}.txt in the binary at compile time
+embed = []
+
+[dependencies]
+aoc-core = {{ path = "../aoc-core" }}
+"#
+    )
+}
+
+/// Replace the placeholder day 0 of the template with `day`
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("const DAY: u8 = 0;", &format!("const DAY: u8 = {};", day))
+        .replace("Day00", &format!("Day{:02}", day))
+        .replace("day00", &format!("day{:02}", day))
+}
+
+/// Insert `line` among the lines starting with `prefix` found in the region
+/// of `text` delimited by the lines starting with `start` and `end`, keeping
+/// them sorted.
+///
+/// Fails if the region is missing or a line with the same key, i.e. the text
+/// up to the first non-alphanumeric character after `prefix`, is present.
+fn insert_sorted(
+    text: &str,
+    (start, end): (&str, &str),
+    prefix: &str,
+    line: &str,
+) -> Result<String, String> {
+    let key = |l: &str| {
+        let l = l.trim();
+        let rest = &l[prefix.len()..];
+        let len = rest
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(rest.len());
+        l[..prefix.len() + len].to_owned()
+    };
+
+    let mut lines = text.lines().collect::<Vec<_>>();
+    let first = lines
+        .iter()
+        .position(|l| l.trim().starts_with(start))
+        .ok_or_else(|| format!("cannot find `{}`", start))?
+        + 1;
+    let last = lines[first..]
+        .iter()
+        .position(|l| l.trim().starts_with(end))
+        .map_or(lines.len(), |i| first + i);
+
+    let new = key(line);
+    let mut at = last;
+    for (i, l) in lines.iter().enumerate().take(last).skip(first) {
+        if !l.trim().starts_with(prefix) {
+            continue;
+        }
+        let existing = key(l);
+        if existing == new {
+            return Err(format!("{} is already registered", new));
+        }
+        if existing > new {
+            at = i;
+            break;
+        }
+        at = i + 1;
+    }
+
+    lines.insert(at, line);
+    let mut text = lines.join("\n");
+    text.push('\n');
+    Ok(text)
+}
+
+#[test]
+fn test_render() {
+    let template = "const DAY: u8 = 0;\nday00::Day00\ninputs/day00.txt";
+    assert_eq!(
+        render(template, 7),
+        "const DAY: u8 = 7;\nday07::Day07\ninputs/day07.txt"
+    );
+}
+
+#[test]
+fn test_insert_sorted() {
+    let manifest = "[workspace]\n\nmembers = [\n    \"aoc\",\n    \"day01\",\n    \"day03\",\n]\n";
+    assert_eq!(
+        insert_sorted(manifest, ("members = [", "]"), "\"day", "    \"day02\","),
+        Ok("[workspace]\n\nmembers = [\n    \"aoc\",\n    \"day01\",\n    \"day02\",\n    \"day03\",\n]\n".to_owned())
+    );
+    assert_eq!(
+        insert_sorted(manifest, ("members = [", "]"), "\"day", "    \"day04\","),
+        Ok("[workspace]\n\nmembers = [\n    \"aoc\",\n    \"day01\",\n    \"day03\",\n    \"day04\",\n]\n".to_owned())
+    );
+    assert!(insert_sorted(manifest, ("members = [", "]"), "\"day", "    \"day03\",").is_err());
+}
+
+#[test]
+fn test_insert_sorted_until_eof() {
+    let manifest =
+        "[dependencies]\naoc-core = { path = \"../aoc-core\" }\nday15 = { path = \"../day15\" }";
+    assert_eq!(
+        insert_sorted(manifest, ("[dependencies]", "["), "day", "day16 = { path = \"../day16\" }"),
+        Ok("[dependencies]\naoc-core = { path = \"../aoc-core\" }\nday15 = { path = \"../day15\" }\nday16 = { path = \"../day16\" }\n".to_owned())
+    );
+}
+
+#[test]
+fn test_insert_sorted_entry() {
+    let days = "pub fn all() -> Vec<Entry> {\n    vec![\n        entry!(day15, day15::Day15::default()),\n    ]\n}\n";
+    assert!(insert_sorted(
+        days,
+        ("pub fn all()", "]"),
+        "entry!(day",
+        "        entry!(day15, day15::Day15),"
+    )
+    .is_err());
+    assert_eq!(
+        insert_sorted(days, ("pub fn all()", "]"), "entry!(day", "        entry!(day16, day16::Day16),"),
+        Ok("pub fn all() -> Vec<Entry> {\n    vec![\n        entry!(day15, day15::Day15::default()),\n        entry!(day16, day16::Day16),\n    ]\n}\n".to_owned())
+    );
+}
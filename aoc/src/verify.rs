@@ -0,0 +1,246 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_core::{input, parse::Mode, Part};
+
+use crate::days::Entry;
+
+/// Default location of the known answers, relative to the repository root
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Name of the real puzzle input, read from `inputs/dayNN.txt`. Every other
+/// input name refers to `dayNN/src/<name>.txt`.
+const INPUT: &str = "input";
+
+/// Expected answers by day, input name and part.
+///
+/// The file has a table for each input of a day, e.g.
+///
+/// ```toml
+/// [day01.testdata]
+/// part1 = 24000
+/// part2 = 45000
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers(BTreeMap<u8, BTreeMap<String, BTreeMap<Part, String>>>);
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| format!("cannot read {}: {}", path.display(), err))?;
+        text.parse()
+            .map_err(|err| format!("{}: {}", path.display(), err))
+    }
+
+    /// Names of the inputs with known answers for `day`
+    fn inputs(&self, day: u8) -> impl Iterator<Item = &str> {
+        self.0
+            .get(&day)
+            .into_iter()
+            .flat_map(|inputs| inputs.keys().map(String::as_str))
+    }
+
+    fn get(&self, day: u8, input: &str, part: Part) -> Option<&str> {
+        self.0.get(&day)?.get(input)?.get(&part).map(String::as_str)
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let table = s.parse::<toml::Table>().map_err(|err| err.to_string())?;
+        let mut answers = Answers::default();
+
+        for (day_key, inputs) in table {
+            let day = day_key
+                .strip_prefix("day")
+                .and_then(|n| n.parse::<u8>().ok())
+                .ok_or_else(|| format!("expected a table named dayNN, found '{}'", day_key))?;
+            let inputs = inputs
+                .as_table()
+                .ok_or_else(|| format!("{}: expected a table of inputs", day_key))?;
+
+            for (input, parts) in inputs {
+                let parts = parts
+                    .as_table()
+                    .ok_or_else(|| format!("{}.{}: expected a table of parts", day_key, input))?;
+
+                for (part_key, value) in parts {
+                    let part = match part_key.as_str() {
+                        "part1" => Part::One,
+                        "part2" => Part::Two,
+                        _ => {
+                            return Err(format!(
+                                "{}.{}: expected part1 or part2, found '{}'",
+                                day_key, input, part_key
+                            ))
+                        }
+                    };
+                    let value = match value {
+                        toml::Value::String(s) => s.clone(),
+                        toml::Value::Integer(n) => n.to_string(),
+                        _ => {
+                            return Err(format!(
+                                "{}.{}.{}: expected a string or an integer",
+                                day_key, input, part_key
+                            ))
+                        }
+                    };
+
+                    answers
+                        .0
+                        .entry(day)
+                        .or_default()
+                        .entry(input.clone())
+                        .or_default()
+                        .insert(part, value);
+                }
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+/// Outcome of checking one answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    /// The answer differs from the expected one, or could not be computed
+    Fail {
+        expected: String,
+        found: String,
+    },
+    /// There is no expected answer, or no input to compute it from
+    Missing(String),
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail { expected, found } => {
+                write!(f, "FAIL  expected {}, found {}", expected, found)
+            }
+            Status::Missing(reason) => write!(f, "missing  {}", reason),
+        }
+    }
+}
+
+/// Result of checking one part of a day on one input
+pub struct Check {
+    pub day: u8,
+    pub input: String,
+    pub part: Part,
+    pub status: Status,
+}
+
+/// Solve every part of `entries` on each of their inputs, strictly, and
+/// compare the answers with the expected ones.
+///
+/// The real input of every day is always checked, so that days without
+/// known answers are reported as missing.
+pub fn verify(entries: &[Entry], answers: &Answers, inputs: &Path) -> Vec<Check> {
+    let mut checks = vec![];
+
+    for entry in entries {
+        let day = entry.solution.day();
+        let mut names = vec![INPUT];
+        names.extend(answers.inputs(day).filter(|&name| name != INPUT));
+
+        for name in names {
+            let known = Part::ALL
+                .iter()
+                .any(|&part| answers.get(day, name, part).is_some());
+            let text = match (name, entry.embedded) {
+                _ if !known => Err("no known answer".to_owned()),
+                (INPUT, Some(embedded)) => Ok(embedded.to_owned()),
+                (INPUT, None) => input::load(day, None, inputs).map_err(|err| err.to_string()),
+                _ => {
+                    let path = testdata_path(day, name);
+                    fs::read_to_string(&path)
+                        .map_err(|err| format!("cannot read {}: {}", path.display(), err))
+                }
+            };
+
+            let report = text.map(|text| entry.solution.solve(&text, &Part::ALL, Mode::Strict));
+
+            for part in Part::ALL {
+                let expected = answers.get(day, name, part);
+                let status = match (expected, &report) {
+                    (None, _) => Status::Missing("no known answer".to_owned()),
+                    (Some(_), Err(err)) => Status::Missing(err.clone()),
+                    (Some(expected), Ok(report)) => {
+                        let answer = report.answers.iter().find(|a| a.part == part);
+                        match answer.map(|a| &a.value) {
+                            Some(Ok(value)) if value == expected => Status::Pass,
+                            Some(Ok(value)) => Status::Fail {
+                                expected: expected.to_owned(),
+                                found: value.clone(),
+                            },
+                            Some(Err(err)) => Status::Fail {
+                                expected: expected.to_owned(),
+                                found: format!("error: {}", err),
+                            },
+                            None => Status::Missing("part was not solved".to_owned()),
+                        }
+                    }
+                };
+
+                checks.push(Check {
+                    day,
+                    input: name.to_owned(),
+                    part,
+                    status,
+                });
+            }
+        }
+    }
+
+    checks
+}
+
+/// Path of the example input `name` of `day`, relative to the repository root
+fn testdata_path(day: u8, name: &str) -> PathBuf {
+    Path::new(&format!("day{:02}", day))
+        .join("src")
+        .join(format!("{}.txt", name))
+}
+
+#[test]
+fn test_parse_answers() {
+    let answers =
+        "[day05.testdata]\npart1 = \"CMZ\"\npart2 = \"MCD\"\n\n[day07.input]\npart1 = 1792222\n"
+            .parse::<Answers>()
+            .unwrap();
+
+    assert_eq!(answers.get(5, "testdata", Part::Two), Some("MCD"));
+    assert_eq!(answers.get(7, "input", Part::One), Some("1792222"));
+    assert_eq!(answers.get(7, "input", Part::Two), None);
+    assert_eq!(answers.inputs(5).collect::<Vec<_>>(), vec!["testdata"]);
+}
+
+#[test]
+fn test_parse_answers_error() {
+    assert!("[day05.testdata]\npart3 = 1".parse::<Answers>().is_err());
+    assert!("[five.testdata]\npart1 = 1".parse::<Answers>().is_err());
+    assert!("[day05.testdata]\npart1 = 1.5".parse::<Answers>().is_err());
+}
+
+#[test]
+fn test_answers_file() {
+    let answers = include_str!("../../answers.toml")
+        .parse::<Answers>()
+        .unwrap();
+    let days = crate::days::all()
+        .iter()
+        .map(|e| e.solution.day())
+        .collect::<Vec<_>>();
+
+    assert!(answers.0.keys().all(|day| days.contains(day)));
+}
@@ -0,0 +1,53 @@
+use aoc_core::{
+    parse::{self, Mode, ParseError},
+    Solution, SolveError,
+};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    /// Total calories carried by each elf
+    type Input = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, input: &str, mode: Mode) -> Result<Vec<u32>, ParseError> {
+        // in lenient mode an invalid line is skipped, not the whole elf
+        input
+            .split("\n\n")
+            .filter(|group| !group.trim().is_empty())
+            .map(|group| {
+                let calories = parse::items(input, group.lines(), mode, |line| {
+                    parse::token::<u32>(line, line, "a number of calories")
+                })?;
+                Ok(calories.iter().sum())
+            })
+            .collect()
+    }
+
+    fn part1(&self, calories: &Vec<u32>) -> Result<u32, SolveError> {
+        calories
+            .iter()
+            .max()
+            .copied()
+            .ok_or_else(|| SolveError::Invalid("there are no elves".to_owned()))
+    }
+
+    fn part2(&self, calories: &Vec<u32>) -> Result<u32, SolveError> {
+        let mut calories = calories.clone();
+
+        calories.sort_unstable();
+        Ok(calories.iter().rev().take(3).sum())
+    }
+}
+
+#[test]
+fn test_lenient_parse() {
+    let input = "1000\n2000\nabc\n3000\n\n4000";
+    assert_eq!(Day01.parse(input, Mode::Lenient), Ok(vec![6000, 4000]));
+
+    let err = Day01.parse(input, Mode::Strict).unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (3, 1, "abc"));
+}
@@ -1,24 +1,10 @@
-fn main() {
-    let input = include_str!("input.txt");
+use std::process::ExitCode;
 
-    println!("Problem 1: {}", problem1(input));
-    println!("Problem 2: {}", problem2(input));
-}
-
-fn problem1(input: &str) -> u32 {
-    input
-        .split("\n\n")
-        .map(|group| group.lines().flat_map(|s| s.parse::<u32>()).sum())
-        .max()
-        .unwrap()
-}
-
-fn problem2(input: &str) -> u32 {
-    let mut calories: Vec<u32> = input
-        .split("\n\n")
-        .map(|group| group.lines().flat_map(|s| s.parse::<u32>()).sum())
-        .collect();
+#[cfg(feature = "embed")]
+const EMBEDDED: Option<&str> = Some(include_str!("../../inputs/day01.txt"));
+#[cfg(not(feature = "embed"))]
+const EMBEDDED: Option<&str> = None;
 
-    calories.sort_unstable();
-    calories.iter().rev().take(3).sum()
+fn main() -> ExitCode {
+    aoc_core::main(&day01::Day01, EMBEDDED)
 }
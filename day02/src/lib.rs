@@ -0,0 +1,186 @@
+use std::str::FromStr;
+
+use aoc_core::{
+    parse::{self, Mode, ParseError},
+    Solution, SolveError,
+};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum What {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(Debug)]
+pub enum RoundResult {
+    Win,
+    Tie,
+    Loss,
+}
+
+#[derive(Debug)]
+pub struct Round1 {
+    opponent: What,
+    me: What,
+}
+
+#[derive(Debug)]
+pub struct Round2 {
+    opponent: What,
+    result: RoundResult,
+}
+
+impl FromStr for What {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" | "X" => Ok(What::Rock),
+            "B" | "Y" => Ok(What::Paper),
+            "C" | "Z" => Ok(What::Scissors),
+            _ => Err(ParseError::new(s, s, "one of A, B, C, X, Y or Z")),
+        }
+    }
+}
+impl FromStr for Round1 {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((first, second)) = s.split_once(' ') else {
+            return Err(ParseError::new(s, s, "two moves separated by a space"));
+        };
+
+        let first = first.parse::<What>().map_err(|e| e.within(s, first))?;
+        let second = second.parse::<What>().map_err(|e| e.within(s, second))?;
+
+        Ok(Round1 {
+            opponent: first,
+            me: second,
+        })
+    }
+}
+impl FromStr for RoundResult {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "X" => Ok(Self::Loss),
+            "Y" => Ok(Self::Tie),
+            "Z" => Ok(Self::Win),
+            _ => Err(ParseError::new(s, s, "one of X, Y or Z")),
+        }
+    }
+}
+impl FromStr for Round2 {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((first, second)) = s.split_once(' ') else {
+            return Err(ParseError::new(
+                s,
+                s,
+                "a move and a result separated by a space",
+            ));
+        };
+
+        let first = first.parse::<What>().map_err(|e| e.within(s, first))?;
+        let second = second
+            .parse::<RoundResult>()
+            .map_err(|e| e.within(s, second))?;
+
+        Ok(Round2 {
+            opponent: first,
+            result: second,
+        })
+    }
+}
+
+impl Round1 {
+    fn result(&self) -> RoundResult {
+        if self.opponent == self.me {
+            RoundResult::Tie
+        } else if match self.me {
+            What::Rock => self.opponent == What::Scissors,
+            What::Paper => self.opponent == What::Rock,
+            What::Scissors => self.opponent == What::Paper,
+        } {
+            RoundResult::Win
+        } else {
+            RoundResult::Loss
+        }
+    }
+}
+
+impl Round2 {
+    fn me(&self) -> What {
+        match self.result {
+            RoundResult::Win => match self.opponent {
+                What::Rock => What::Paper,
+                What::Paper => What::Scissors,
+                What::Scissors => What::Rock,
+            },
+            RoundResult::Tie => self.opponent,
+            RoundResult::Loss => match self.opponent {
+                What::Rock => What::Scissors,
+                What::Paper => What::Rock,
+                What::Scissors => What::Paper,
+            },
+        }
+    }
+}
+
+trait Points {
+    fn points(&self) -> u32;
+}
+
+impl Points for What {
+    fn points(&self) -> u32 {
+        match self {
+            What::Rock => 1,
+            What::Paper => 2,
+            What::Scissors => 3,
+        }
+    }
+}
+
+impl Points for RoundResult {
+    fn points(&self) -> u32 {
+        match self {
+            RoundResult::Win => 6,
+            RoundResult::Tie => 3,
+            RoundResult::Loss => 0,
+        }
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    /// Every line read with both strategy guide interpretations
+    type Input = Vec<(Round1, Round2)>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, input: &str, mode: Mode) -> Result<Self::Input, ParseError> {
+        parse::items(input, input.lines(), mode, |s| {
+            Ok((s.parse::<Round1>()?, s.parse::<Round2>()?))
+        })
+    }
+
+    fn part1(&self, rounds: &Self::Input) -> Result<u32, SolveError> {
+        Ok(rounds
+            .iter()
+            .map(|(r, _)| r.me.points() + r.result().points())
+            .sum::<u32>())
+    }
+
+    fn part2(&self, rounds: &Self::Input) -> Result<u32, SolveError> {
+        Ok(rounds
+            .iter()
+            .map(|(_, r)| r.me().points() + r.result.points())
+            .sum::<u32>())
+    }
+}
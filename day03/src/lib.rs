@@ -0,0 +1,71 @@
+use aoc_core::{
+    parse::{self, Mode, ParseError},
+    Solution, SolveError,
+};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    /// One rucksack per line
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, input: &str, mode: Mode) -> Result<Vec<String>, ParseError> {
+        parse::items(input, input.lines(), mode, |l| {
+            match l.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+                Some((i, c)) => Err(ParseError::new(
+                    l,
+                    &l[i..i + c.len_utf8()],
+                    "an item type between 'a' and 'Z'",
+                )),
+                None => Ok(l.to_owned()),
+            }
+        })
+    }
+
+    fn part1(&self, rucksacks: &Vec<String>) -> Result<u32, SolveError> {
+        Ok(rucksacks
+            .iter()
+            .map(|l| l.split_at(l.len() / 2))
+            .flat_map(|x| x.0.chars().find(|c| x.1.contains(*c)))
+            .map(priority)
+            .sum())
+    }
+
+    fn part2(&self, rucksacks: &Vec<String>) -> Result<u32, SolveError> {
+        Ok(rucksacks
+            .chunks_exact(3)
+            .flat_map(find_badge)
+            .map(priority)
+            .sum())
+    }
+}
+
+const fn priority(char: char) -> u32 {
+    match char {
+        'a'..='z' => 1 + (char as u32) - ('a' as u32),
+        'A'..='Z' => 27 + (char as u32) - ('A' as u32),
+        _ => 0,
+    }
+}
+
+#[test]
+fn test_priorities() {
+    assert_eq!(priority('a'), 1);
+    assert_eq!(priority('z'), 26);
+    assert_eq!(priority('A'), 27);
+    assert_eq!(priority('Z'), 52);
+}
+
+fn find_badge(group: &[String]) -> Option<char> {
+    if let [first, second, third] = group {
+        first
+            .chars()
+            .find(|&c| second.contains(c) && third.contains(c))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,70 @@
+use std::str::FromStr;
+
+use aoc_core::{
+    parse::{self, Mode, ParseError},
+    Solution, SolveError,
+};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    /// Section assignments of each pair of elves
+    type Input = Vec<(Range, Range)>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, input: &str, mode: Mode) -> Result<Self::Input, ParseError> {
+        parse::items(input, input.lines(), mode, |l| {
+            let Some((a, b)) = l.split_once(',') else {
+                return Err(ParseError::new(l, l, "two ranges separated by ','"));
+            };
+
+            Ok((
+                a.parse::<Range>().map_err(|e| e.within(l, a))?,
+                b.parse::<Range>().map_err(|e| e.within(l, b))?,
+            ))
+        })
+    }
+
+    fn part1(&self, pairs: &Self::Input) -> Result<u32, SolveError> {
+        Ok(pairs
+            .iter()
+            .filter(|(a, b)| a.contains(b) || b.contains(a))
+            .count() as u32)
+    }
+
+    fn part2(&self, pairs: &Self::Input) -> Result<u32, SolveError> {
+        Ok(pairs.iter().filter(|(a, b)| a.overlaps(b)).count() as u32)
+    }
+}
+
+pub struct Range {
+    start: u32,
+    end: u32,
+}
+
+impl Range {
+    fn contains(&self, other: &Range) -> bool {
+        self.start <= other.start && self.end >= other.end
+    }
+
+    fn overlaps(&self, other: &Range) -> bool {
+        !(self.start > other.end || self.end < other.start)
+    }
+}
+impl FromStr for Range {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((start, end)) = s.split_once('-') else {
+            return Err(ParseError::new(s, s, "a range like '2-4'"));
+        };
+
+        Ok(Self {
+            start: parse::token(s, start, "a section number")?,
+            end: parse::token(s, end, "a section number")?,
+        })
+    }
+}
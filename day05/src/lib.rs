@@ -0,0 +1,180 @@
+use std::{str::FromStr, vec};
+
+use aoc_core::{
+    parse::{self, Mode, ParseError},
+    Solution, SolveError,
+};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    /// Starting stacks and the rearrangement procedure
+    type Input = (Vec<Vec<char>>, Vec<Rearrangement>);
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(&self, input: &str, mode: Mode) -> Result<Self::Input, ParseError> {
+        let Some((stacks, rearrangement)) = input.split_once("\n\n") else {
+            return Err(ParseError::new(
+                input,
+                &input[input.len()..],
+                "stacks and rearrangements separated by an empty line",
+            ));
+        };
+
+        let rearrangement = parse::items(input, rearrangement.lines(), mode, str::parse)?;
+
+        Ok((load_stacks(stacks)?, rearrangement))
+    }
+
+    fn part1(&self, (stacks, rearrangement): &Self::Input) -> Result<String, SolveError> {
+        let mut stacks = stacks.clone();
+
+        for r in rearrangement {
+            for _ in 0..(r.qty) {
+                let char = pop(&mut stacks, r.from)?;
+                stack(&mut stacks, r.to)?.push(char);
+            }
+        }
+
+        Ok(stacks.iter_mut().flat_map(|s| s.pop()).collect())
+    }
+
+    fn part2(&self, (stacks, rearrangement): &Self::Input) -> Result<String, SolveError> {
+        let mut stacks = stacks.clone();
+
+        let mut temp_stack: Vec<char> = Vec::default();
+
+        for r in rearrangement {
+            for _ in 0..(r.qty) {
+                temp_stack.push(pop(&mut stacks, r.from)?)
+            }
+
+            stack(&mut stacks, r.to)?.extend(temp_stack.drain(..).rev());
+        }
+
+        Ok(stacks.iter_mut().flat_map(|s| s.pop()).collect())
+    }
+}
+
+/// Stack number `n`, counting from 1
+fn stack(stacks: &mut [Vec<char>], n: u32) -> Result<&mut Vec<char>, SolveError> {
+    (n as usize)
+        .checked_sub(1)
+        .and_then(|i| stacks.get_mut(i))
+        .ok_or_else(|| SolveError::Invalid(format!("stack {} does not exist", n)))
+}
+
+/// Take the crate on top of stack number `n`
+fn pop(stacks: &mut [Vec<char>], n: u32) -> Result<char, SolveError> {
+    stack(stacks, n)?.pop().ok_or_else(|| {
+        SolveError::Invalid(format!("stack {} is empty. cannot move any more items", n))
+    })
+}
+
+#[test]
+fn test_problem1() {
+    assert_eq!(
+        Day05.problem1(include_str!("testdata.txt")),
+        Ok("CMZ".to_owned())
+    );
+}
+
+#[test]
+fn test_problem2() {
+    assert_eq!(
+        Day05.problem2(include_str!("testdata.txt")),
+        Ok("MCD".to_owned())
+    );
+}
+
+pub struct Rearrangement {
+    qty: u32,
+    from: u32,
+    to: u32,
+}
+
+impl FromStr for Rearrangement {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let ["move", qty, "from", from, "to", to] = s.split_whitespace().collect::<Vec<_>>()[..]
+        else {
+            return Err(ParseError::new(
+                s,
+                s,
+                "'move <qty> from <stack> to <stack>'",
+            ));
+        };
+
+        Ok(Rearrangement {
+            qty: parse::token(s, qty, "a quantity")?,
+            from: parse::token(s, from, "a stack number")?,
+            to: parse::token(s, to, "a stack number")?,
+        })
+    }
+}
+
+fn load_stacks(stacks: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let Some(numbers) = stacks.lines().last() else {
+        return Err(ParseError::new(
+            stacks,
+            stacks,
+            "a line with the stack numbers",
+        ));
+    };
+    let nstacks = numbers.split_whitespace().count();
+    let mut vec_stacks: Vec<Vec<char>> = vec![Vec::default(); nstacks];
+
+    for (i, vec) in vec_stacks.iter_mut().enumerate() {
+        let char_idx = 1 + i * 4;
+
+        for char in stacks
+            .lines()
+            .rev()
+            .skip(1)
+            .map(|l| l.as_bytes().get(char_idx).map_or(' ', |&c| c as char))
+        {
+            if char != ' ' {
+                vec.push(char)
+            }
+        }
+    }
+
+    Ok(vec_stacks)
+}
+
+#[test]
+fn test_load_stacks() {
+    let (stacks, _) = include_str!("testdata.txt")
+        .split_once("\n\n")
+        .expect("invalid input. expected double newline");
+
+    let mut stacks = load_stacks(stacks).unwrap();
+
+    assert_eq!(stacks[0].pop(), Some('N'));
+    assert_eq!(stacks[0].pop(), Some('Z'));
+    assert_eq!(stacks[0].pop(), None);
+
+    assert_eq!(stacks[1].pop(), Some('D'));
+    assert_eq!(stacks[1].pop(), Some('C'));
+    assert_eq!(stacks[1].pop(), Some('M'));
+    assert_eq!(stacks[1].pop(), None);
+
+    assert_eq!(stacks[2].pop(), Some('P'));
+    assert_eq!(stacks[2].pop(), None);
+}
+
+#[test]
+fn test_invalid_rearrangement() {
+    let input = "[A]\n 1   2 \n\nmove 2 from 1 to 2";
+    assert_eq!(
+        Day05.problem1(input),
+        Err(SolveError::Invalid(
+            "stack 1 is empty. cannot move any more items".to_owned()
+        ))
+    );
+    assert!(Day05.problem2("[A]\n 1 \n\nmove 1 from 2 to 1").is_err());
+}
@@ -0,0 +1,60 @@
+use aoc_core::{
+    parse::{Mode, ParseError},
+    Solution, SolveError,
+};
+use itertools::Itertools;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    /// Datastream buffer
+    type Input = String;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, input: &str, _mode: Mode) -> Result<String, ParseError> {
+        Ok(input.trim_end().to_owned())
+    }
+
+    fn part1(&self, input: &String) -> Result<u32, SolveError> {
+        let first_unique_pattern = find_unique_pattern(input, 4)
+            .ok_or_else(|| SolveError::Invalid("Cannot find pattern".to_owned()))?
+            as u32;
+        Ok(first_unique_pattern + 4)
+    }
+
+    fn part2(&self, input: &String) -> Result<u32, SolveError> {
+        let first_unique_pattern = find_unique_pattern(input, 14)
+            .ok_or_else(|| SolveError::Invalid("Cannot find pattern".to_owned()))?
+            as u32;
+        Ok(first_unique_pattern + 14)
+    }
+}
+
+#[test]
+fn test_problem1() {
+    assert_eq!(Day06.problem1("bvwbjplbgvbhsrlpgdmjqwftvncz"), Ok(5));
+    assert_eq!(Day06.problem1("nppdvjthqldpwncqszvftbrmjlhg"), Ok(6));
+    assert_eq!(Day06.problem1("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), Ok(10));
+    assert_eq!(Day06.problem1("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), Ok(11));
+}
+
+#[test]
+fn test_problem2() {
+    assert_eq!(Day06.problem2("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), Ok(19));
+    assert_eq!(Day06.problem2("bvwbjplbgvbhsrlpgdmjqwftvncz"), Ok(23));
+    assert_eq!(Day06.problem2("nppdvjthqldpwncqszvftbrmjlhg"), Ok(23));
+    assert_eq!(Day06.problem2("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), Ok(29));
+    assert_eq!(Day06.problem2("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), Ok(26));
+}
+
+fn find_unique_pattern(s: &str, size: usize) -> Option<usize> {
+    s.as_bytes()
+        .windows(size)
+        .enumerate()
+        .filter(|(_, n)| n.iter().unique().count() == size)
+        .map(|(i, _)| i)
+        .next()
+}
@@ -0,0 +1,203 @@
+use std::{
+    cell::RefCell,
+    rc::{Rc, Weak},
+    vec,
+};
+
+use aoc_core::{
+    parse::{self, Mode, ParseError},
+    Solution, SolveError,
+};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    /// Root of the filesystem
+    type Input = Rc<FS>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str, _mode: Mode) -> Result<Rc<FS>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, fs: &Rc<FS>) -> Result<usize, SolveError> {
+        Ok(fs
+            .flat_dirs()
+            .iter()
+            .filter(|d| d.size() < 100000)
+            .map(|d| d.size())
+            .sum())
+    }
+
+    fn part2(&self, fs: &Rc<FS>) -> Result<usize, SolveError> {
+        let used_space = fs.size();
+        fs.flat_dirs()
+            .iter()
+            .filter(|f| 70000000usize.saturating_sub(used_space - f.size()) >= 30000000)
+            .map(|d| d.size())
+            .min()
+            .ok_or_else(|| SolveError::Invalid("no directory frees up enough space".to_owned()))
+    }
+}
+
+#[test]
+fn test_problem1() {
+    assert_eq!(Day07.problem1(include_str!("testdata.txt")), Ok(95437));
+}
+
+#[test]
+fn test_problem2() {
+    assert_eq!(Day07.problem2(include_str!("testdata.txt")), Ok(24933642));
+}
+
+#[derive(Debug)]
+pub enum FS {
+    // note: RefCell is used because children are added afterwards
+    // and we need the Vec to mutate
+    Dir(String, RefCell<Vec<Rc<FS>>>, Option<Weak<FS>>),
+    File(String, usize),
+}
+
+impl FS {
+    /// Return file size or total directory size
+    fn size(&self) -> usize {
+        match self {
+            FS::File(_, size) => *size,
+            FS::Dir(_, files, _) => files.borrow().iter().map(|f| f.size()).sum(),
+        }
+    }
+
+    /// Return file name or directory name
+    fn name(&self) -> &String {
+        match self {
+            FS::File(name, _) => name,
+            FS::Dir(name, _, _) => name,
+        }
+    }
+
+    /// Return child that matches name.
+    ///
+    /// The function does NOT iterate inner directories
+    fn find_child(&self, name: &str) -> Option<Rc<FS>> {
+        match self {
+            FS::File(..) => None,
+            FS::Dir(_, children, _) => children.borrow().iter().find(|f| f.name() == name).cloned(),
+        }
+    }
+
+    /// Return directory's parent
+    fn parent(&self) -> Option<Rc<FS>> {
+        match self {
+            FS::Dir(_, _, Some(parent)) => parent.upgrade(),
+            _ => None,
+        }
+    }
+
+    /// Push child if self is directory
+    fn push_child(&self, child: FS) {
+        if let FS::Dir(_, children, _) = self {
+            children.borrow_mut().push(Rc::new(child))
+        }
+    }
+
+    /// Returns `true` if the fs is [`Dir`].
+    ///
+    /// [`Dir`]: FS::Dir
+    #[must_use]
+    fn is_dir(&self) -> bool {
+        matches!(self, Self::Dir(..))
+    }
+
+    /// Return all directories and sub-directories in self
+    fn flat_dirs(&self) -> Vec<Rc<FS>> {
+        match self {
+            FS::Dir(_, children, _) => children
+                .borrow()
+                .iter()
+                .filter(|x| x.is_dir())
+                .flat_map(|x| x.flat_dirs().into_iter().chain(vec![x.clone()]))
+                .collect::<Vec<_>>(),
+            FS::File(..) => vec![],
+        }
+    }
+}
+
+fn parse_input(s: &str) -> Result<Rc<FS>, ParseError> {
+    let fs = Rc::new(FS::Dir(String::from("/"), RefCell::new(vec![]), None));
+
+    let mut cwd = fs.clone();
+
+    for line in s.lines() {
+        let end_of_line = &line[line.len()..];
+        let mut tokens = line.split_whitespace();
+        match tokens.next() {
+            None => {}
+            Some("$") => match tokens.next() {
+                Some("cd") => {
+                    cwd = match tokens.next() {
+                        Some("..") => cwd
+                            .parent()
+                            .ok_or_else(|| ParseError::new(s, line, "a directory with a parent"))?,
+                        Some("/") => fs.clone(),
+                        Some(param) => cwd
+                            .find_child(param)
+                            .filter(|child| child.is_dir())
+                            .ok_or_else(|| ParseError::new(s, param, "an existing directory"))?,
+                        None => return Err(ParseError::new(s, end_of_line, "a 'cd' argument")),
+                    }
+                }
+                Some("ls") => {}
+                cmd => {
+                    return Err(ParseError::new(
+                        s,
+                        cmd.unwrap_or(end_of_line),
+                        "'cd' or 'ls'",
+                    ))
+                }
+            },
+            Some(_) => {
+                let Some((a, name)) = line.split_once(' ') else {
+                    return Err(ParseError::new(s, line, "'dir <name>' or '<size> <name>'"));
+                };
+
+                cwd.push_child(match a {
+                    "dir" => FS::Dir(
+                        String::from(name),
+                        RefCell::new(vec![]),
+                        Some(Rc::downgrade(&cwd)),
+                    ),
+                    _ => FS::File(String::from(name), parse::token(s, a, "a file size")?),
+                })
+            }
+        }
+    }
+    Ok(fs)
+}
+
+#[test]
+fn test_flat_dirs() {
+    let fs = parse_input(
+        "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d",
+    )
+    .unwrap();
+
+    let dirs = fs.flat_dirs();
+    let mut dir_names = dirs.iter().map(|d| d.name().to_owned());
+
+    assert_eq!(dir_names.next(), Some("a".to_string()));
+    assert_eq!(dir_names.next(), Some("d".to_string()));
+}
+
+#[test]
+fn test_parse_unknown_dir() {
+    let err = parse_input("$ cd /\n$ ls\ndir a\n$ cd b").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (4, 6, "b"));
+}
@@ -0,0 +1,253 @@
+use std::str::FromStr;
+
+use aoc_core::{
+    geometry::Direction,
+    grid::Grid,
+    parse::{Mode, ParseError},
+    Solution, SolveError,
+};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str, _mode: Mode) -> Result<Map, ParseError> {
+        input.parse::<Map>()
+    }
+
+    fn part1(&self, map: &Map) -> Result<usize, SolveError> {
+        Ok(map.visible().cells().iter().filter(|&&v| v).count())
+    }
+
+    fn part2(&self, map: &Map) -> Result<usize, SolveError> {
+        Ok(map
+            .scenic_scores()
+            .cells()
+            .iter()
+            .copied()
+            .max()
+            .unwrap_or_default())
+    }
+}
+
+pub struct Map {
+    trees: Grid<u8>,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trees = Grid::parse(s, |c| c.to_digit(10).map(|h| h as u8), "a tree height")?;
+        Ok(Map { trees })
+    }
+}
+
+impl Map {
+    /// Whether each tree is visible from outside the forest.
+    ///
+    /// Sweeps every row and column from both ends, keeping track of the
+    /// tallest tree so far, in O(w·h).
+    pub fn visible(&self) -> Grid<bool> {
+        let heights = self.trees.cells();
+        let mut visible = vec![false; heights.len()];
+
+        for line in self.sightlines() {
+            let mut tallest = None;
+            for i in line {
+                if tallest.is_none_or(|t| heights[i] > t) {
+                    visible[i] = true;
+                    tallest = Some(heights[i]);
+                }
+            }
+        }
+
+        Grid::from_vec(self.trees.width(), visible).expect("same size as the forest")
+    }
+
+    /// Scenic score of every tree.
+    ///
+    /// Sweeps every row and column from both ends with a stack of the trees
+    /// not hidden yet by a taller one, in O(w·h).
+    pub fn scenic_scores(&self) -> Grid<usize> {
+        let heights = self.trees.cells();
+        let mut scores = vec![1; heights.len()];
+
+        for line in self.sightlines() {
+            // Offsets in `line` of the trees that can still block the view,
+            // strictly decreasing in height
+            let mut stack: Vec<usize> = vec![];
+            for (k, &i) in line.iter().enumerate() {
+                while stack
+                    .last()
+                    .is_some_and(|&top| heights[line[top]] < heights[i])
+                {
+                    stack.pop();
+                }
+                // Looking back: up to the edge, or to the first tree at
+                // least as tall
+                scores[i] *= stack.last().map_or(k, |&top| k - top);
+                stack.push(k);
+            }
+        }
+
+        Grid::from_vec(self.trees.width(), scores).expect("same size as the forest")
+    }
+
+    /// Indices of the trees along every row and column, in both directions
+    fn sightlines(&self) -> impl Iterator<Item = Vec<usize>> {
+        let (width, height) = (self.trees.width(), self.trees.height());
+        let rows = (0..height).map(move |y| (0..width).map(|x| y * width + x).collect::<Vec<_>>());
+        let columns =
+            (0..width).map(move |x| (0..height).map(|y| y * width + x).collect::<Vec<_>>());
+
+        rows.chain(columns).flat_map(|line| {
+            let reversed = line.iter().rev().copied().collect();
+            [line, reversed]
+        })
+    }
+
+    /// Number of edges the tree at `index` is visible from, or 1 if it is on
+    /// an edge.
+    ///
+    /// Scans the row and column of the tree; see [`Map::visible`] for the
+    /// whole forest.
+    pub fn visibility(&self, index: usize) -> u32 {
+        let pos = self.trees.pos_of(index);
+
+        if pos.x == 0
+            || pos.x == self.trees.width() - 1
+            || pos.y == 0
+            || pos.y == self.trees.height() - 1
+        {
+            return 1;
+        }
+
+        let height = self.trees[pos];
+        Direction::ALL
+            .into_iter()
+            .filter(|d| {
+                self.trees
+                    .ray(pos, d.delta())
+                    .all(|p| self.trees[p] < height)
+            })
+            .count() as u32
+    }
+
+    /// Number of trees seen from the tree at `index` looking towards
+    /// `direction`, up to the edge or the first tree at least as tall
+    pub fn viewing_distance(&self, index: usize, direction: Direction) -> usize {
+        let pos = self.trees.pos_of(index);
+        let height = self.trees[pos];
+
+        let mut distance = 0;
+        for p in self.trees.ray(pos, direction.delta()) {
+            distance += 1;
+            if self.trees[p] >= height {
+                break;
+            }
+        }
+        distance
+    }
+
+    /// Product of the viewing distances in every direction.
+    ///
+    /// Scans the row and column of the tree; see [`Map::scenic_scores`] for
+    /// the whole forest.
+    pub fn scenic_score(&self, index: usize) -> usize {
+        Direction::ALL
+            .into_iter()
+            .map(|d| self.viewing_distance(index, d))
+            .product()
+    }
+}
+
+/// Map of a random `width` by `height` forest, the same for a given `seed`
+pub fn random_forest(width: usize, height: usize, seed: u64) -> String {
+    // xorshift64, which must not start from 0
+    let mut state = seed.max(1);
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    (0..height)
+        .map(|_| {
+            (0..width)
+                .map(|_| char::from(b'0' + (next() % 10) as u8))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn test_problem1() {
+    let input = include_str!("testdata.txt");
+    assert_eq!(input.parse::<Map>().unwrap().trees.cells().len(), 25);
+    assert_eq!(Day08.problem1(input), Ok(21));
+}
+
+#[test]
+fn test_problem2() {
+    assert_eq!(Day08.problem2(include_str!("testdata.txt")), Ok(8));
+}
+
+#[test]
+fn test_visibility() {
+    let map = include_str!("testdata.txt").parse::<Map>().unwrap();
+
+    assert_eq!(map.visibility(0), 1);
+    assert_eq!(map.visibility(4), 1);
+    assert_eq!(map.visibility(6), 2);
+    assert_eq!(map.visibility(7), 2);
+    assert_eq!(map.visibility(11), 1);
+    assert_eq!(map.visibility(20), 1);
+    assert_eq!(map.visibility(24), 1);
+
+    assert_eq!(map.visibility(17), 2);
+}
+
+#[test]
+fn test_scenic_score() {
+    let map = include_str!("testdata.txt").parse::<Map>().unwrap();
+
+    assert_eq!(map.viewing_distance(7, Direction::Up), 1);
+    assert_eq!(map.viewing_distance(7, Direction::Left), 1);
+    assert_eq!(map.viewing_distance(7, Direction::Right), 2);
+    assert_eq!(map.viewing_distance(7, Direction::Down), 2);
+    assert_eq!(map.scenic_score(7), 4);
+
+    assert_eq!(map.viewing_distance(17, Direction::Up), 2);
+    assert_eq!(map.viewing_distance(17, Direction::Left), 2);
+    assert_eq!(map.viewing_distance(17, Direction::Down), 1);
+    assert_eq!(map.viewing_distance(17, Direction::Right), 2);
+    assert_eq!(map.scenic_score(17), 8);
+
+    // trees on an edge see nothing in at least one direction
+    assert_eq!(map.viewing_distance(0, Direction::Up), 0);
+    assert_eq!(map.scenic_score(0), 0);
+    assert_eq!(map.scenic_score(24), 0);
+}
+
+#[test]
+fn test_sweeps_match_scans() {
+    for (width, height, seed) in [(1, 1, 1), (1, 7, 2), (9, 1, 3), (120, 80, 4)] {
+        let map = random_forest(width, height, seed).parse::<Map>().unwrap();
+        let visible = map.visible();
+        let scores = map.scenic_scores();
+
+        for i in 0..width * height {
+            let pos = map.trees.pos_of(i);
+            assert_eq!(visible[pos], map.visibility(i) > 0, "visibility of {}", pos);
+            assert_eq!(scores[pos], map.scenic_score(i), "scenic score of {}", pos);
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//! Draw a simulated rope.
+//!
+//! ```text
+//! cargo run -p day09 --example render -- <input> <knots> [<svg-dir> [--frames]]
+//! ```
+//!
+//! Prints the final knots and the squares visited by the tail, and writes
+//! `rope.svg` to `<svg-dir>` if given. With `--frames`, also writes
+//! `frame-NNNN.svg` after each movement.
+
+use std::{env, fs, path::PathBuf, process::ExitCode};
+
+use aoc_core::parse::{self, Mode};
+use day09::{Movement, Rope};
+
+fn main() -> ExitCode {
+    match render(env::args().skip(1).collect()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn render(args: Vec<String>) -> Result<(), String> {
+    let (input, knots, dir, frames) = match &args[..] {
+        [input, knots] => (input, knots, None, false),
+        [input, knots, dir] => (input, knots, Some(dir), false),
+        [input, knots, dir, flag] if flag == "--frames" => (input, knots, Some(dir), true),
+        _ => return Err("usage: render <input> <knots> [<svg-dir> [--frames]]".to_owned()),
+    };
+
+    let knots = match knots.parse::<usize>() {
+        Ok(knots) if knots > 0 => knots,
+        _ => {
+            return Err(format!(
+                "expected a positive number of knots, found '{}'",
+                knots
+            ))
+        }
+    };
+    let text =
+        fs::read_to_string(input).map_err(|err| format!("cannot read {}: {}", input, err))?;
+    let movements: Vec<Movement> =
+        parse::lines(&text, Mode::Lenient).map_err(|err| format!("{}: {}", input, err))?;
+
+    let rope = Rope::simulate(knots, &movements);
+    println!("{}\n\n{}", rope.render_knots(), rope.render_visited());
+
+    let Some(dir) = dir.map(PathBuf::from) else {
+        return Ok(());
+    };
+    fs::create_dir_all(&dir).map_err(|err| format!("cannot create {}: {}", dir.display(), err))?;
+
+    let write = |name: String, svg: String| {
+        let path = dir.join(name);
+        fs::write(&path, svg).map_err(|err| format!("cannot write {}: {}", path.display(), err))
+    };
+    write("rope.svg".to_owned(), rope.to_svg())?;
+    if frames {
+        for (i, svg) in Rope::svg_frames(knots, &movements).into_iter().enumerate() {
+            write(format!("frame-{:04}.svg", i + 1), svg)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,187 @@
+use std::str::FromStr;
+
+use aoc_core::{
+    geometry::{Direction, Point},
+    parse::{self, Mode, ParseError},
+    Solution, SolveError,
+};
+use itertools::Itertools;
+
+mod render;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Movement>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str, mode: Mode) -> Result<Vec<Movement>, ParseError> {
+        parse::lines(input, mode)
+    }
+
+    fn part1(&self, movements: &Vec<Movement>) -> Result<usize, SolveError> {
+        Ok(Rope::simulate(2, movements).tail().iter().unique().count())
+    }
+
+    fn part2(&self, movements: &Vec<Movement>) -> Result<usize, SolveError> {
+        Ok(Rope::simulate(10, movements).tail().iter().unique().count())
+    }
+}
+
+pub type Pos = Point<i32>;
+
+/// Rope made of knots, each one following the previous one
+#[derive(Debug, Clone)]
+pub struct Rope {
+    knots: Vec<Pos>,
+    /// Positions of every knot, at the start and after each step
+    history: Vec<Vec<Pos>>,
+}
+
+impl Rope {
+    /// Rope of `knots` knots, at least one, all at the origin
+    pub fn new(knots: usize) -> Self {
+        assert!(knots > 0, "a rope needs at least one knot");
+
+        let start = Pos::default();
+        Self {
+            knots: vec![start; knots],
+            history: vec![vec![start]; knots],
+        }
+    }
+
+    /// Rope of `knots` knots after all `movements` of its head
+    pub fn simulate(knots: usize, movements: &[Movement]) -> Self {
+        let mut rope = Self::new(knots);
+        for m in movements {
+            rope.apply(m);
+        }
+        rope
+    }
+
+    /// Current position of every knot, from the head
+    pub fn knots(&self) -> &[Pos] {
+        &self.knots
+    }
+
+    /// Positions of knot `knot`, the head being 0, at the start and after
+    /// each step
+    pub fn history(&self, knot: usize) -> &[Pos] {
+        &self.history[knot]
+    }
+
+    pub fn tail(&self) -> &[Pos] {
+        self.history(self.knots.len() - 1)
+    }
+
+    pub fn apply(&mut self, movement: &Movement) {
+        for _ in 0..movement.steps {
+            self.step(movement.direction);
+        }
+    }
+
+    /// Move the head one step towards `direction`.
+    ///
+    /// A knot no longer touching the previous one moves one step towards it
+    /// along each axis where they differ, diagonally if needed.
+    pub fn step(&mut self, direction: Direction) {
+        self.knots[0] += direction.delta();
+
+        for i in 1..self.knots.len() {
+            let (leader, knot) = (self.knots[i - 1], self.knots[i]);
+            if knot.chebyshev(leader) > 1 {
+                self.knots[i] += (leader - knot).signum();
+            }
+        }
+
+        for (history, knot) in self.history.iter_mut().zip(&self.knots) {
+            history.push(*knot);
+        }
+    }
+}
+
+pub struct Movement {
+    direction: Direction,
+    steps: u8,
+}
+
+impl FromStr for Movement {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((direction, steps)) = s.split_once(' ') else {
+            return Err(ParseError::new(s, s, "a direction and a number of steps"));
+        };
+        let steps = parse::token::<u8>(s, steps, "a number of steps")?;
+
+        let direction = match direction {
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            "D" => Direction::Down,
+            "U" => Direction::Up,
+            _ => return Err(ParseError::new(s, direction, "one of L, R, D or U")),
+        };
+
+        Ok(Self { direction, steps })
+    }
+}
+
+#[test]
+fn test_problem1() {
+    assert_eq!(Day09.problem1(include_str!("testdata.txt")), Ok(13));
+}
+
+#[test]
+fn test_problem2() {
+    assert_eq!(Day09.problem2(include_str!("testdata2.txt")), Ok(36));
+}
+
+#[test]
+fn test_long_rope_on_short_moves() {
+    // the tail of a 10-knot rope never moves in the first example
+    let movements = parse::lines(include_str!("testdata.txt"), Mode::Strict).unwrap();
+    assert_eq!(
+        Rope::simulate(10, &movements).tail(),
+        &[Pos::default(); 25][..]
+    );
+}
+
+#[test]
+fn test_rope() {
+    let movements = parse::lines(include_str!("testdata.txt"), Mode::Strict).unwrap();
+    let rope = Rope::simulate(2, &movements);
+
+    assert_eq!(rope.knots(), &[Pos::new(2, -2), Pos::new(1, -2)]);
+    assert_eq!(rope.history(0).len(), 25);
+    assert_eq!(
+        rope.tail()[..4],
+        [
+            Pos::new(0, 0),
+            Pos::new(0, 0),
+            Pos::new(1, 0),
+            Pos::new(2, 0)
+        ]
+    );
+}
+
+#[test]
+fn test_diagonal_follow() {
+    let mut rope = Rope::new(3);
+    for direction in [
+        Direction::Right,
+        Direction::Up,
+        Direction::Up,
+        Direction::Up,
+    ] {
+        rope.step(direction);
+    }
+
+    // the middle knot moved diagonally, and dragged the tail diagonally
+    assert_eq!(
+        rope.knots(),
+        &[Pos::new(1, -3), Pos::new(1, -2), Pos::new(1, -1)]
+    );
+}
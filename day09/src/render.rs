@@ -0,0 +1,242 @@
+use std::fmt::Write;
+
+use aoc_core::grid::{self, Grid};
+
+use crate::{Movement, Pos, Rope};
+
+/// Size of a square of the grid in SVG pixels
+const SVG_SCALE: i32 = 10;
+
+/// Top left and bottom right corners of a rectangle
+type Bounds = (Pos, Pos);
+
+impl Rope {
+    /// Smallest rectangle holding every position of every knot so far
+    fn bounds(&self) -> Bounds {
+        self.history
+            .iter()
+            .flatten()
+            .fold((Pos::default(), Pos::default()), |(min, max), p| {
+                (
+                    Pos::new(min.x.min(p.x), min.y.min(p.y)),
+                    Pos::new(max.x.max(p.x), max.y.max(p.y)),
+                )
+            })
+    }
+
+    /// Label of knot `i` in the diagrams of the puzzle: `H` for the head,
+    /// `T` for the tail of a 2-knot rope, or the number of the knot
+    fn label(&self, i: usize) -> char {
+        match (i, self.knots.len()) {
+            (0, _) => 'H',
+            (1, 2) => 'T',
+            _ => char::from_digit(i as u32 % 36, 36).unwrap_or('?'),
+        }
+    }
+
+    /// Current knots over the start `s`, as in the diagrams of the puzzle.
+    /// Knots closer to the head hide the ones behind them.
+    pub fn render_knots(&self) -> String {
+        let mut canvas = Canvas::new(self.bounds());
+        for (i, &knot) in self.knots.iter().enumerate().rev() {
+            canvas.draw(knot, self.label(i));
+        }
+        canvas.finish()
+    }
+
+    /// Positions visited by the tail as `#`, with the start `s`
+    pub fn render_visited(&self) -> String {
+        let mut canvas = Canvas::new(self.bounds());
+        for &position in self.tail() {
+            canvas.draw(position, '#');
+        }
+        canvas.draw(Pos::default(), 's');
+        canvas.finish()
+    }
+
+    /// SVG image of the squares visited by the tail, its path, and the
+    /// current knots
+    pub fn to_svg(&self) -> String {
+        self.svg_within(self.bounds())
+    }
+
+    /// SVG image of the rope after each of `movements`, all covering the
+    /// same area so they can be viewed frame by frame
+    pub fn svg_frames(knots: usize, movements: &[Movement]) -> Vec<String> {
+        let bounds = Rope::simulate(knots, movements).bounds();
+        let mut rope = Rope::new(knots);
+
+        movements
+            .iter()
+            .map(|m| {
+                rope.apply(m);
+                rope.svg_within(bounds)
+            })
+            .collect()
+    }
+
+    fn svg_within(&self, (min, max): Bounds) -> String {
+        let (width, height) = (max.x - min.x + 1, max.y - min.y + 1);
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\" width=\"{}\" height=\"{}\">\n",
+            min.x,
+            min.y,
+            width,
+            height,
+            width * SVG_SCALE,
+            height * SVG_SCALE
+        );
+        // Writing to a `String` cannot fail, hence the ignored results
+        let _ = writeln!(
+            svg,
+            "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"#0f0f23\"/>",
+            min.x, min.y, width, height
+        );
+
+        let mut visited = self.tail().to_vec();
+        visited.sort_unstable();
+        visited.dedup();
+        for p in visited {
+            let _ = writeln!(
+                svg,
+                "<rect x=\"{}\" y=\"{}\" width=\"1\" height=\"1\" fill=\"#333340\"/>",
+                p.x, p.y
+            );
+        }
+
+        let mut path = self.tail().to_vec();
+        path.dedup();
+        let points = path
+            .iter()
+            .map(|&p| {
+                let (x, y) = centre(p);
+                format!("{},{}", x, y)
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        let _ = writeln!(
+            svg,
+            "<polyline points=\"{}\" fill=\"none\" stroke=\"#ffff66\" stroke-width=\"0.2\"/>",
+            points
+        );
+
+        for (i, &knot) in self.knots.iter().enumerate().rev() {
+            let fill = if i == 0 { "#ff6666" } else { "#66ccff" };
+            let (x, y) = centre(knot);
+            let _ = writeln!(
+                svg,
+                "<circle cx=\"{x}\" cy=\"{y}\" r=\"0.4\" fill=\"{fill}\"/>\n\
+                 <text x=\"{x}\" y=\"{y}\" font-size=\"0.6\" text-anchor=\"middle\" \
+                 dominant-baseline=\"central\">{label}</text>",
+                x = x,
+                y = y,
+                fill = fill,
+                label = self.label(i)
+            );
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+/// Centre of the square at `p`, which covers `p` to `p + (1, 1)`
+fn centre(p: Pos) -> (f64, f64) {
+    (p.x as f64 + 0.5, p.y as f64 + 0.5)
+}
+
+/// ASCII grid covering some bounds, empty but for the start
+struct Canvas {
+    min: Pos,
+    grid: Grid<char>,
+}
+
+impl Canvas {
+    fn new((min, max): Bounds) -> Self {
+        let width = (max.x - min.x + 1) as usize;
+        let height = (max.y - min.y + 1) as usize;
+        let mut canvas = Self {
+            min,
+            grid: Grid::filled(width, height, '.'),
+        };
+        canvas.draw(Pos::default(), 's');
+        canvas
+    }
+
+    fn draw(&mut self, p: Pos, c: char) {
+        let cell = grid::Pos::new((p.x - self.min.x) as usize, (p.y - self.min.y) as usize);
+        self.grid[cell] = c;
+    }
+
+    fn finish(self) -> String {
+        self.grid.to_string()
+    }
+}
+
+#[cfg(test)]
+fn example() -> Rope {
+    use aoc_core::parse::{self, Mode};
+
+    let movements = parse::lines(include_str!("testdata.txt"), Mode::Strict).unwrap();
+    Rope::simulate(2, &movements)
+}
+
+#[test]
+fn test_render_knots() {
+    assert_eq!(
+        example().render_knots(),
+        "......\n......\n.TH...\n......\ns....."
+    );
+    assert_eq!(Rope::new(10).render_knots(), "H");
+}
+
+#[test]
+fn test_render_visited() {
+    assert_eq!(
+        example().render_visited(),
+        "..##..\n...##.\n.####.\n....#.\ns###.."
+    );
+}
+
+#[test]
+fn test_render_long_rope() {
+    use aoc_core::parse::{self, Mode};
+
+    let movements = parse::lines(include_str!("testdata2.txt"), Mode::Strict).unwrap();
+    let rope = Rope::simulate(10, &movements);
+    let rows = rope.render_visited();
+    let rows = rows.lines().collect::<Vec<_>>();
+
+    assert_eq!(rows.len(), 21);
+    assert_eq!(rows[15], "....#......s.........#....");
+    assert_eq!(rows[20], ".........########.........");
+    let empty = ".".repeat(25);
+    assert!(rope
+        .render_knots()
+        .starts_with(&format!("H{}\n1{}", empty, empty)));
+}
+
+#[test]
+fn test_svg() {
+    use aoc_core::parse::{self, Mode};
+
+    let svg = example().to_svg();
+    assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 -4 6 5""#));
+    assert_eq!(svg.matches("<circle").count(), 2);
+    assert!(svg.ends_with("</svg>\n"));
+    // the head is at (2, -2) and the tail at (1, -2), in squares ending at -1
+    assert!(svg.contains(r#"<circle cx="2.5" cy="-1.5""#));
+    assert!(svg.contains(r#"<text x="1.5" y="-1.5""#));
+    assert!(svg.contains(r#"<polyline points="0.5,0.5 1.5,0.5 2.5,0.5 3.5,0.5 4.5,-0.5"#));
+    assert!(svg.contains(r#" 1.5,-1.5" fill="none""#));
+
+    let climb = Rope::simulate(2, &parse::lines("U 3", Mode::Strict).unwrap()).to_svg();
+    assert!(climb.contains(r#"viewBox="0 -3 1 4""#));
+    assert!(climb.contains(r#"<circle cx="0.5" cy="-2.5""#));
+    assert!(climb.contains(r#"<polyline points="0.5,0.5 0.5,-0.5 0.5,-1.5""#));
+
+    let movements = parse::lines("R 2\nU 1", Mode::Strict).unwrap();
+    let frames = Rope::svg_frames(2, &movements);
+    assert_eq!(frames.len(), 2);
+    assert!(frames.iter().all(|f| f.contains(r#"viewBox="0 -1 3 2""#)));
+}
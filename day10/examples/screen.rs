@@ -0,0 +1,66 @@
+//! Export the screen drawn by a program as an image.
+//!
+//! ```text
+//! cargo run -p day10 [--features png] --example screen -- <input> <image.pbm|image.png> [--scale <n>] [--on <rrggbb>] [--off <rrggbb>]
+//! ```
+
+use std::{env, fs, path::Path, process::ExitCode};
+
+use aoc_core::parse::{self, Mode};
+use day10::{draw_crt, Instruction, Rgb, Style};
+
+fn main() -> ExitCode {
+    match export(env::args().skip(1).collect()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn export(args: Vec<String>) -> Result<(), String> {
+    let [input, image, options @ ..] = &args[..] else {
+        return Err(
+            "usage: screen <input> <image> [--scale <n>] [--on <rrggbb>] [--off <rrggbb>]"
+                .to_owned(),
+        );
+    };
+
+    let mut style = Style::default();
+    for option in options.chunks(2) {
+        match option {
+            [name, value] if name == "--scale" => {
+                style.scale = match value.parse() {
+                    Ok(scale) if scale > 0 => scale,
+                    _ => return Err(format!("expected a positive scale, found '{}'", value)),
+                }
+            }
+            [name, value] if name == "--on" => style.on = colour(value)?,
+            [name, value] if name == "--off" => style.off = colour(value)?,
+            _ => return Err(format!("unexpected option '{}'", option.join(" "))),
+        }
+    }
+
+    let text =
+        fs::read_to_string(input).map_err(|err| format!("cannot read {}: {}", input, err))?;
+    let program: Vec<Instruction> =
+        parse::lines(&text, Mode::Lenient).map_err(|err| format!("{}: {}", input, err))?;
+
+    draw_crt(&program)
+        .save(Path::new(image), &style)
+        .map_err(|err| format!("cannot write {}: {}", image, err))
+}
+
+/// Colour written as 6 hexadecimal digits, optionally after a `#`
+fn colour(s: &str) -> Result<Rgb, String> {
+    let hex = s.strip_prefix('#').unwrap_or(s);
+    let component = |i: usize| {
+        hex.get(i..i + 2)
+            .and_then(|c| u8::from_str_radix(c, 16).ok())
+    };
+    match (hex.len(), component(0), component(2), component(4)) {
+        (6, Some(r), Some(g), Some(b)) => Ok([r, g, b]),
+        _ => Err(format!("expected a colour like 'ff8800', found '{}'", s)),
+    }
+}
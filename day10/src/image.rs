@@ -0,0 +1,160 @@
+use std::{fs, io, path::Path};
+
+use crate::Crt;
+
+/// Colour as red, green and blue components
+pub type Rgb = [u8; 3];
+
+/// How the screen is drawn in an image
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Style {
+    /// Size in image pixels of the side of a screen pixel, at least 1
+    pub scale: usize,
+    pub on: Rgb,
+    pub off: Rgb,
+}
+
+impl Default for Style {
+    /// Black on white, 4 image pixels per screen pixel
+    fn default() -> Self {
+        Self {
+            scale: 4,
+            on: [0, 0, 0],
+            off: [255, 255, 255],
+        }
+    }
+}
+
+impl Crt {
+    /// Whether each pixel of the image is lit, row by row
+    fn scaled(&self, scale: usize) -> impl Iterator<Item = Vec<bool>> + '_ {
+        let scale = scale.max(1);
+        self.rows().flat_map(move |row| {
+            let line = row
+                .iter()
+                .flat_map(|&lit| std::iter::repeat_n(lit, scale))
+                .collect::<Vec<_>>();
+            std::iter::repeat_n(line, scale)
+        })
+    }
+
+    /// Binary Netpbm bitmap of the screen.
+    ///
+    /// A bitmap is only black and white: lit pixels are black if `on` is
+    /// darker than `off`, and white otherwise.
+    pub fn to_pbm(&self, style: &Style) -> Vec<u8> {
+        let scale = style.scale.max(1);
+        let black = luma(style.on) <= luma(style.off);
+
+        let mut pbm =
+            format!("P4\n{} {}\n", Self::WIDTH * scale, Self::HEIGHT * scale).into_bytes();
+        for line in self.scaled(scale) {
+            // each row is padded to whole bytes, 1 meaning black
+            for chunk in line.chunks(8) {
+                let byte = chunk
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, &lit)| lit == black)
+                    .fold(0u8, |byte, (i, _)| byte | 0x80 >> i);
+                pbm.push(byte);
+            }
+        }
+        pbm
+    }
+
+    /// PNG image of the screen, in colour
+    #[cfg(feature = "png")]
+    pub fn to_png(&self, style: &Style) -> Vec<u8> {
+        let scale = style.scale.max(1);
+        let mut png = Vec::new();
+
+        let mut encoder = png::Encoder::new(
+            &mut png,
+            (Self::WIDTH * scale) as u32,
+            (Self::HEIGHT * scale) as u32,
+        );
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let data = self
+            .scaled(scale)
+            .flatten()
+            .flat_map(|lit| if lit { style.on } else { style.off })
+            .collect::<Vec<_>>();
+        // Encoding into memory cannot fail once the header matches the data
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&data))
+            .expect("PNG encoding failed");
+
+        png
+    }
+
+    /// Write the screen to `path`, as a PBM or PNG image depending on its
+    /// extension
+    pub fn save(&self, path: &Path, style: &Style) -> io::Result<()> {
+        let extension = path.extension().and_then(|e| e.to_str());
+        let image = match extension.map(str::to_ascii_lowercase).as_deref() {
+            Some("pbm") => self.to_pbm(style),
+            #[cfg(feature = "png")]
+            Some("png") => self.to_png(style),
+            _ => {
+                let expected = if cfg!(feature = "png") {
+                    "a .pbm or .png file"
+                } else {
+                    "a .pbm file, or .png with the png feature"
+                };
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("expected {}", expected),
+                ));
+            }
+        };
+        fs::write(path, image)
+    }
+}
+
+/// Perceived brightness of `colour`, from 0 to 255
+fn luma([r, g, b]: Rgb) -> u32 {
+    (299 * r as u32 + 587 * g as u32 + 114 * b as u32) / 1000
+}
+
+#[cfg(test)]
+fn example() -> Crt {
+    use aoc_core::parse::{self, Mode};
+
+    crate::draw_crt(&parse::lines(include_str!("testdata.txt"), Mode::Strict).unwrap())
+}
+
+#[test]
+fn test_pbm() {
+    let pbm = example().to_pbm(&Style {
+        scale: 2,
+        ..Style::default()
+    });
+    let header = b"P4\n80 12\n";
+    assert_eq!(&pbm[..header.len()], header);
+    assert_eq!(pbm.len(), header.len() + 10 * 12);
+    // ##..##.. doubled, then the same row again
+    assert_eq!(
+        pbm[header.len()..header.len() + 2],
+        [0b11110000, 0b11110000]
+    );
+    assert_eq!(pbm[header.len() + 10], 0b11110000);
+
+    let inverted = example().to_pbm(&Style {
+        scale: 1,
+        on: [255, 255, 0],
+        off: [0, 0, 64],
+    });
+    assert_eq!(inverted[b"P4\n40 6\n".len()], 0b00110011);
+}
+
+#[cfg(feature = "png")]
+#[test]
+fn test_png() {
+    let png = example().to_png(&Style::default());
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    // width and height in the IHDR chunk
+    assert_eq!(&png[16..24], [0, 0, 0, 160, 0, 0, 0, 24]);
+}
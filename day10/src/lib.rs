@@ -0,0 +1,239 @@
+use std::{fmt::Display, str::FromStr};
+
+use aoc_core::{
+    parse::{self, Mode, ParseError},
+    Solution, SolveError,
+};
+
+mod image;
+mod ocr;
+
+pub use image::{Rgb, Style};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Instruction>;
+    type Answer1 = i32;
+    type Answer2 = String;
+
+    fn parse(&self, input: &str, mode: Mode) -> Result<Vec<Instruction>, ParseError> {
+        parse::lines(input, mode)
+    }
+
+    fn part1(&self, instr: &Vec<Instruction>) -> Result<i32, SolveError> {
+        // signal strength during the 20th cycle and every 40 cycles after,
+        // up to the 220th
+        Ok(Cpu::new(instr)
+            .take_while(|&(cycle, _)| cycle <= 220)
+            .filter(|&(cycle, _)| cycle % 40 == 20)
+            .map(|(cycle, x)| cycle as i32 * x)
+            .sum())
+    }
+
+    fn part2(&self, instr: &Vec<Instruction>) -> Result<String, SolveError> {
+        draw_crt(instr).read()
+    }
+}
+
+#[test]
+fn test_problem1() {
+    assert_eq!(Day10.problem1(include_str!("testdata.txt")), Ok(13140));
+}
+
+#[test]
+fn test_problem2() {
+    assert_eq!(
+        Day10.problem2(include_str!("testdata2.txt")),
+        Ok("RKAZAJBR".to_owned())
+    );
+}
+
+#[test]
+fn test_draw_crt() {
+    let program = parse::lines(include_str!("testdata.txt"), Mode::Strict).unwrap();
+    assert_eq!(
+        draw_crt(&program).to_string(),
+        [
+            "##..##..##..##..##..##..##..##..##..##..",
+            "###...###...###...###...###...###...###.",
+            "####....####....####....####....####....",
+            "#####.....#####.....#####.....#####.....",
+            "######......######......######......####",
+            "#######.......#######.......#######.....",
+        ]
+        .join("\n")
+    );
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Noop,
+    Add(i32),
+}
+
+impl Instruction {
+    /// Number of cycles taken to complete, at least 1
+    pub fn cycles(self) -> usize {
+        match self {
+            Instruction::Noop => 1,
+            Instruction::Add(_) => 2,
+        }
+    }
+
+    /// Value of the X register once completed, from its value `x` before
+    pub fn execute(self, x: i32) -> i32 {
+        match self {
+            Instruction::Noop => x,
+            Instruction::Add(num) => x + num,
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = s.split(' ');
+        match tokens.next() {
+            Some("noop") => Ok(Self::Noop),
+            Some("addx") => Ok(Self::Add(parse::token(
+                s,
+                tokens.next().unwrap_or(&s[s.len()..]),
+                "a number",
+            )?)),
+            _ => Err(ParseError::new(s, s, "noop or addx")),
+        }
+    }
+}
+
+/// Processor of the device, running a program one cycle at a time.
+///
+/// Iterating yields the number of each cycle, from 1, along with the value
+/// of the X register during it. An instruction only updates X once its last
+/// cycle is over.
+#[derive(Debug, Clone)]
+pub struct Cpu<'a> {
+    program: &'a [Instruction],
+    /// Index of the running instruction in `program`
+    pc: usize,
+    /// Cycles already spent on the running instruction
+    elapsed: usize,
+    cycle: usize,
+    x: i32,
+}
+
+impl<'a> Cpu<'a> {
+    pub fn new(program: &'a [Instruction]) -> Self {
+        Self {
+            program,
+            pc: 0,
+            elapsed: 0,
+            cycle: 0,
+            x: 1,
+        }
+    }
+
+    /// Current value of the X register
+    pub fn x(&self) -> i32 {
+        self.x
+    }
+}
+
+impl Iterator for Cpu<'_> {
+    type Item = (usize, i32);
+
+    fn next(&mut self) -> Option<(usize, i32)> {
+        let instr = *self.program.get(self.pc)?;
+        self.cycle += 1;
+        let during = (self.cycle, self.x);
+
+        self.elapsed += 1;
+        if self.elapsed >= instr.cycles() {
+            self.x = instr.execute(self.x);
+            self.pc += 1;
+            self.elapsed = 0;
+        }
+
+        Some(during)
+    }
+}
+
+/// Image drawn on the screen of the device
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Crt {
+    pixels: Vec<bool>,
+}
+
+impl Crt {
+    pub const WIDTH: usize = 40;
+    pub const HEIGHT: usize = 6;
+
+    /// Whether the pixel in column `x` of row `y` is lit
+    pub fn lit(&self, x: usize, y: usize) -> bool {
+        x < Self::WIDTH && self.pixels.get(y * Self::WIDTH + x) == Some(&true)
+    }
+
+    /// Pixels of each row, from the top
+    pub fn rows(&self) -> impl Iterator<Item = &[bool]> {
+        self.pixels.chunks(Self::WIDTH)
+    }
+}
+
+impl Display for Crt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for &lit in row {
+                write!(f, "{}", if lit { '#' } else { '.' })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Screen drawn by running `program`, lighting the pixel being drawn during
+/// each cycle when the 3 pixels wide sprite, centered on X, covers it
+pub fn draw_crt(program: &[Instruction]) -> Crt {
+    let mut pixels = Cpu::new(program)
+        .take(Crt::WIDTH * Crt::HEIGHT)
+        .map(|(cycle, x)| {
+            let column = ((cycle - 1) % Crt::WIDTH) as i32;
+            (column - x).abs() <= 1
+        })
+        .collect::<Vec<_>>();
+    pixels.resize(Crt::WIDTH * Crt::HEIGHT, false);
+
+    Crt { pixels }
+}
+
+#[test]
+fn test_cpu() {
+    let program = [Instruction::Noop, Instruction::Add(3), Instruction::Add(-5)];
+    let mut cpu = Cpu::new(&program);
+    assert_eq!(
+        cpu.by_ref().collect::<Vec<_>>(),
+        vec![(1, 1), (2, 1), (3, 1), (4, 4), (5, 4)]
+    );
+    assert_eq!(cpu.x(), -1);
+}
+
+#[test]
+fn test_trace() {
+    let instr: Vec<Instruction> = parse::lines(include_str!("testdata.txt"), Mode::Strict).unwrap();
+    let trace = Cpu::new(&instr).collect::<Vec<_>>();
+    for (cycle, x) in [
+        (20, 21),
+        (60, 19),
+        (100, 18),
+        (140, 21),
+        (180, 16),
+        (220, 18),
+    ] {
+        assert_eq!(trace[cycle - 1], (cycle, x));
+    }
+}
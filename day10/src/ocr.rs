@@ -0,0 +1,92 @@
+use aoc_core::SolveError;
+
+use crate::Crt;
+
+/// Width in pixels of a letter, which is followed by an unlit column
+const GLYPH_WIDTH: usize = 4;
+
+/// Letters known to be drawn by the puzzles, as their lit (`#`) and unlit
+/// (`.`) pixels row by row
+const FONT: [(char, &str); 17] = [
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('I', ".###..#...#...#...#..###"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+impl Crt {
+    /// Letters shown on the screen, every 5 columns from the left
+    pub fn read(&self) -> Result<String, SolveError> {
+        let letters = Self::WIDTH / (GLYPH_WIDTH + 1);
+
+        (0..letters)
+            .map(|i| {
+                let left = i * (GLYPH_WIDTH + 1);
+                let glyph = self
+                    .rows()
+                    .map(|row| {
+                        row[left..left + GLYPH_WIDTH]
+                            .iter()
+                            .map(|&lit| if lit { '#' } else { '.' })
+                            .collect::<String>()
+                    })
+                    .collect::<Vec<_>>();
+
+                let pixels = glyph.concat();
+                FONT.iter()
+                    .find(|(_, font)| *font == pixels)
+                    .map(|&(letter, _)| letter)
+                    .ok_or_else(|| {
+                        SolveError::Invalid(format!(
+                            "unknown glyph for letter {} of the screen:\n{}",
+                            i + 1,
+                            glyph.join("\n")
+                        ))
+                    })
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn test_read() {
+    use aoc_core::parse::{self, Mode};
+
+    let program = parse::lines(include_str!("testdata2.txt"), Mode::Strict).unwrap();
+    assert_eq!(crate::draw_crt(&program).read(), Ok("RKAZAJBR".to_owned()));
+
+    let program = parse::lines(include_str!("testdata.txt"), Mode::Strict).unwrap();
+    let Err(SolveError::Invalid(err)) = crate::draw_crt(&program).read() else {
+        panic!("the example does not show letters");
+    };
+    assert!(err.starts_with("unknown glyph for letter 1 of the screen:\n##..\n###.\n"));
+}
+
+#[test]
+fn test_read_font() {
+    for (letter, font) in FONT {
+        let font = font.as_bytes();
+        // The letter repeated across the whole screen
+        let pixels = (0..Crt::WIDTH * Crt::HEIGHT)
+            .map(|i| {
+                let (x, y) = (i % Crt::WIDTH % (GLYPH_WIDTH + 1), i / Crt::WIDTH);
+                x < GLYPH_WIDTH && font[y * GLYPH_WIDTH + x] == b'#'
+            })
+            .collect();
+        let expected = letter.to_string().repeat(Crt::WIDTH / (GLYPH_WIDTH + 1));
+        assert_eq!(Crt { pixels }.read(), Ok(expected));
+    }
+}
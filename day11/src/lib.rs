@@ -0,0 +1,514 @@
+use std::{fmt::Display, str::FromStr};
+
+use aoc_core::{
+    parse::{self, Mode, ParseError},
+    Solution, SolveError,
+};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<Monkey>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str, mode: Mode) -> Result<Vec<Monkey>, ParseError> {
+        parse::blocks(input, mode)
+    }
+
+    fn part1(&self, monkeys: &Vec<Monkey>) -> Result<u64, SolveError> {
+        validate(monkeys)?;
+        let mut monkeys = monkeys.clone();
+        play_rounds(&mut monkeys, 20, |worry| worry / 3)?;
+        Ok(monkey_business(monkeys.iter().map(|m| m.rounds).collect()))
+    }
+
+    fn part2(&self, monkeys: &Vec<Monkey>) -> Result<u64, SolveError> {
+        Ok(monkey_business(simulate(monkeys, 10_000)?))
+    }
+}
+
+/// Product of the number of items inspected by the two most active monkeys
+fn monkey_business(mut inspections: Vec<u64>) -> u64 {
+    inspections.sort_unstable();
+    inspections.iter().rev().take(2).product()
+}
+
+/// Worry levels are only ever tested for divisibility, so without the relief
+/// dividing them by 3 they are kept modulo the least common multiple of the
+/// divisors to stay bounded
+fn modulus(monkeys: &[Monkey]) -> Result<usize, SolveError> {
+    monkeys
+        .iter()
+        .try_fold(1, |modulus, m| lcm(modulus, m.test.div_by))
+        .ok_or_else(|| {
+            SolveError::Invalid("the least common multiple of the divisors is too large".to_owned())
+        })
+}
+
+/// Check that monkeys are listed in order and only throw to other monkeys
+/// that exist
+fn validate(monkeys: &[Monkey]) -> Result<(), SolveError> {
+    for (i, monkey) in monkeys.iter().enumerate() {
+        if monkey.id as usize != i {
+            return Err(SolveError::Invalid(format!(
+                "expected monkey {}, found monkey {}",
+                i, monkey.id
+            )));
+        }
+
+        for target in [monkey.test.if_true, monkey.test.if_false] {
+            if target == monkey.id {
+                return Err(SolveError::Invalid(format!(
+                    "monkey {} throws to itself",
+                    monkey.id
+                )));
+            }
+            if target as usize >= monkeys.len() {
+                return Err(SolveError::Invalid(format!(
+                    "monkey {} throws to monkey {}, which does not exist",
+                    monkey.id, target
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Play `rounds` rounds, `relief` lowering every worry level once its item
+/// has been inspected.
+///
+/// The monkeys must have been validated: a monkey throwing to itself would
+/// lose the item.
+fn play_rounds(
+    monkeys: &mut [Monkey],
+    rounds: usize,
+    relief: impl Fn(usize) -> usize,
+) -> Result<(), SolveError> {
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            // nothing is thrown to the monkey during its own turn, so its
+            // emptied list is handed back afterwards to keep its capacity
+            let mut items = std::mem::take(&mut monkeys[i].items);
+            monkeys[i].rounds += items.len() as u64;
+
+            for item in items.drain(..) {
+                let monkey = &monkeys[i];
+                let new = relief(monkey.eval(item)?);
+                let target = monkey.test.target(new);
+                monkeys[target].items.push(new);
+            }
+
+            monkeys[i].items = items;
+        }
+    }
+
+    Ok(())
+}
+
+/// Number of items inspected by each monkey during `rounds` rounds without
+/// relief, for any number of rounds.
+///
+/// Items move independently of each other, and the monkey holding one and
+/// its worry level modulo [`modulus`] can only take finitely many values. So
+/// every item is followed on its own until its path loops, and the
+/// inspections of the loop are multiplied by the times it is repeated.
+pub fn simulate(monkeys: &[Monkey], rounds: u64) -> Result<Vec<u64>, SolveError> {
+    validate(monkeys)?;
+    let modulus = modulus(monkeys)?;
+    let mut inspections = vec![0; monkeys.len()];
+
+    for (holder, monkey) in monkeys.iter().enumerate() {
+        for &worry in &monkey.items {
+            let item = Item {
+                holder,
+                worry: worry % modulus,
+            };
+            track(monkeys, modulus, item, rounds, &mut inspections)?;
+        }
+    }
+
+    Ok(inspections)
+}
+
+/// State of an item at the start of a round
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Item {
+    holder: usize,
+    worry: usize,
+}
+
+/// Follow `item` for one round, counting the inspections of each monkey
+fn round(
+    monkeys: &[Monkey],
+    modulus: usize,
+    item: Item,
+    inspections: &mut [u64],
+) -> Result<Item, SolveError> {
+    let Item {
+        mut holder,
+        mut worry,
+    } = item;
+
+    // an item thrown to a monkey that has not played yet is inspected again
+    // in the same round
+    loop {
+        count(&mut inspections[holder], 1)?;
+        let monkey = &monkeys[holder];
+        worry = monkey.eval(worry)? % modulus;
+
+        let target = monkey.test.target(worry);
+        if target < holder {
+            return Ok(Item {
+                holder: target,
+                worry,
+            });
+        }
+        holder = target;
+    }
+}
+
+/// Add `inspections` to the `total` of a monkey
+fn count(total: &mut u64, inspections: u64) -> Result<(), SolveError> {
+    *total = total.checked_add(inspections).ok_or_else(too_many)?;
+    Ok(())
+}
+
+fn too_many() -> SolveError {
+    SolveError::Invalid("a monkey inspects more than 2^64 items".to_owned())
+}
+
+/// Add the inspections of `item` during `rounds` rounds to `inspections`
+fn track(
+    monkeys: &[Monkey],
+    modulus: usize,
+    item: Item,
+    rounds: u64,
+    inspections: &mut [u64],
+) -> Result<(), SolveError> {
+    let mut ignored = vec![0; monkeys.len()];
+    let follow = |mut item: Item, rounds: u64, inspections: &mut [u64]| {
+        for _ in 0..rounds {
+            item = round(monkeys, modulus, item, inspections)?;
+        }
+        Ok::<_, SolveError>(item)
+    };
+
+    // Brent's algorithm finds the length of the loop, giving up once it
+    // would take longer than playing every round
+    let (mut power, mut length) = (1, 1);
+    let mut tortoise = item;
+    let mut hare = follow(item, 1, &mut ignored)?;
+    let mut steps = 1;
+    while tortoise != hare {
+        if steps >= rounds {
+            follow(item, rounds, inspections)?;
+            return Ok(());
+        }
+        if power == length {
+            tortoise = hare;
+            power *= 2;
+            length = 0;
+        }
+        hare = follow(hare, 1, &mut ignored)?;
+        length += 1;
+        steps += 1;
+    }
+
+    // the loop starts at the first round where the item is in the same state
+    // as `length` rounds later
+    let (mut start, mut ahead) = (0, follow(item, length, &mut ignored)?);
+    let mut looping = item;
+    while looping != ahead && start < rounds {
+        looping = follow(looping, 1, &mut ignored)?;
+        ahead = follow(ahead, 1, &mut ignored)?;
+        start += 1;
+    }
+    if start >= rounds {
+        follow(item, rounds, inspections)?;
+        return Ok(());
+    }
+
+    follow(item, start, inspections)?;
+    let mut per_loop = vec![0; monkeys.len()];
+    follow(looping, length, &mut per_loop)?;
+
+    let (loops, rest) = ((rounds - start) / length, (rounds - start) % length);
+    for (total, per_loop) in inspections.iter_mut().zip(per_loop) {
+        count(total, loops.checked_mul(per_loop).ok_or_else(too_many)?)?;
+    }
+    follow(looping, rest, inspections)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_problem1() {
+    assert_eq!(Day11.problem1(include_str!("testdata.txt")), Ok(10605));
+}
+
+#[test]
+fn test_problem2() {
+    assert_eq!(Day11.problem2(include_str!("testdata.txt")), Ok(2713310158));
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Least common multiple, `None` if it overflows
+fn lcm(a: usize, b: usize) -> Option<usize> {
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    id: u8,
+    items: Vec<usize>,
+    operation: Operation,
+    test: Test,
+    rounds: u64,
+}
+
+impl Monkey {
+    fn eval(&self, old: usize) -> Result<usize, SolveError> {
+        self.operation.apply(old).ok_or_else(|| {
+            SolveError::Invalid(format!(
+                "monkey {}: worry level overflows computing {} with old = {}",
+                self.id, self.operation, old
+            ))
+        })
+    }
+}
+
+/// How a monkey changes the worry level of an item it inspects
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add(Operand),
+    Multiply(Operand),
+}
+
+/// Right-hand side of an [`Operation`], the left one always being `old`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Old,
+    Value(usize),
+}
+
+impl Operation {
+    /// New worry level from the `old` one, `None` if it overflows
+    pub fn apply(self, old: usize) -> Option<usize> {
+        let operand = |operand| match operand {
+            Operand::Old => old,
+            Operand::Value(value) => value,
+        };
+
+        match self {
+            Operation::Add(rhs) => old.checked_add(operand(rhs)),
+            Operation::Multiply(rhs) => old.checked_mul(operand(rhs)),
+        }
+    }
+}
+
+/// Parses `new = old <+|*> <old|number>`
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some(expr) = s.strip_prefix("new = old ") else {
+            return Err(ParseError::new(s, s, "an operation like 'new = old * 19'"));
+        };
+        let Some((operator, rhs)) = expr.split_once(' ') else {
+            return Err(ParseError::new(s, expr, "an operator and an operand"));
+        };
+
+        let rhs = match rhs {
+            "old" => Operand::Old,
+            _ => Operand::Value(parse::token(s, rhs, "'old' or a number")?),
+        };
+        match operator {
+            "+" => Ok(Operation::Add(rhs)),
+            "*" => Ok(Operation::Multiply(rhs)),
+            _ => Err(ParseError::new(s, operator, "'+' or '*'")),
+        }
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (operator, rhs) = match self {
+            Operation::Add(rhs) => ('+', rhs),
+            Operation::Multiply(rhs) => ('*', rhs),
+        };
+        match rhs {
+            Operand::Old => write!(f, "new = old {} old", operator),
+            Operand::Value(value) => write!(f, "new = old {} {}", operator, value),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Test {
+    div_by: usize,
+    if_true: u8,
+    if_false: u8,
+}
+
+impl Test {
+    /// Monkey an item with the `worry` level is thrown to
+    fn target(&self, worry: usize) -> usize {
+        if worry.is_multiple_of(self.div_by) {
+            self.if_true as usize
+        } else {
+            self.if_false as usize
+        }
+    }
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+
+        let header = lines.next().unwrap_or_default();
+        let Some(id) = header
+            .strip_prefix("Monkey ")
+            .and_then(|h| h.strip_suffix(':'))
+        else {
+            return Err(ParseError::new(s, header, "'Monkey <id>:'"));
+        };
+        let id = parse::token::<u8>(s, id, "a monkey id")?;
+
+        let items = field(s, lines.next(), "Starting items:")?
+            .split(',')
+            .map(str::trim)
+            .filter(|i| !i.is_empty())
+            .map(|i| parse::token::<usize>(s, i, "a worry level"))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let operation = field(s, lines.next(), "Operation:")?;
+        let operation = operation
+            .parse::<Operation>()
+            .map_err(|err| err.within(s, operation))?;
+
+        let test = parse_test(s, lines)?;
+
+        Ok(Self {
+            id,
+            items,
+            operation,
+            test,
+            rounds: 0,
+        })
+    }
+}
+
+/// Text of `line` after `prefix`, ignoring indentation
+fn field<'a>(s: &'a str, line: Option<&'a str>, prefix: &str) -> Result<&'a str, ParseError> {
+    let line = line.unwrap_or(&s[s.len()..]);
+    line.trim_start()
+        .strip_prefix(prefix)
+        .map(str::trim)
+        .ok_or_else(|| ParseError::new(s, line, format!("'{}'", prefix)))
+}
+
+fn parse_test<'a>(
+    s: &'a str,
+    mut lines: impl Iterator<Item = &'a str>,
+) -> Result<Test, ParseError> {
+    let div_by = field(s, lines.next(), "Test: divisible by")?;
+    let if_true = field(s, lines.next(), "If true: throw to monkey")?;
+    let if_false = field(s, lines.next(), "If false: throw to monkey")?;
+
+    Ok(Test {
+        div_by: match parse::token(s, div_by, "a number")? {
+            0 => return Err(ParseError::new(s, div_by, "a divisor greater than 0")),
+            div_by => div_by,
+        },
+        if_true: parse::token(s, if_true, "a monkey id")?,
+        if_false: parse::token(s, if_false, "a monkey id")?,
+    })
+}
+
+#[test]
+fn test_operation() {
+    let square = "new = old * old".parse::<Operation>().unwrap();
+    assert_eq!(square, Operation::Multiply(Operand::Old));
+    assert_eq!(square.apply(7), Some(49));
+    assert_eq!(square.apply(usize::MAX), None);
+    assert_eq!(square.to_string(), "new = old * old");
+
+    let add = "new = old + 6".parse::<Operation>().unwrap();
+    assert_eq!(add.apply(1), Some(7));
+
+    let err = "new = old / 2".parse::<Operation>().unwrap_err();
+    assert_eq!((err.column, err.expected.as_str()), (11, "'+' or '*'"));
+    assert!("new = 3 * old".parse::<Operation>().is_err());
+    assert!("new = old * x".parse::<Operation>().is_err());
+}
+
+#[test]
+fn test_validate() {
+    let mut monkeys = Day11
+        .parse(include_str!("testdata.txt"), Mode::Strict)
+        .unwrap();
+    assert_eq!(validate(&monkeys), Ok(()));
+
+    monkeys[1].test.if_false = 1;
+    assert_eq!(
+        Day11.part1(&monkeys),
+        Err(SolveError::Invalid("monkey 1 throws to itself".to_owned()))
+    );
+
+    monkeys[1].test.if_false = 4;
+    assert!(validate(&monkeys).is_err());
+}
+
+#[test]
+fn test_modulus_overflow() {
+    let mut monkeys = Day11
+        .parse(include_str!("testdata.txt"), Mode::Strict)
+        .unwrap();
+    assert_eq!(modulus(&monkeys), Ok(23 * 19 * 13 * 17));
+
+    // four coprime divisors around 2^20 overflow 64 bits
+    for (monkey, div_by) in monkeys
+        .iter_mut()
+        .zip([1 << 20, 1_048_573, 1_048_571, 1_048_569])
+    {
+        monkey.test.div_by = div_by;
+    }
+    assert!(matches!(modulus(&monkeys), Err(SolveError::Invalid(_))));
+    assert!(Day11.part2(&monkeys).is_err());
+}
+
+#[test]
+fn test_simulate() {
+    let monkeys = Day11
+        .parse(include_str!("testdata.txt"), Mode::Strict)
+        .unwrap();
+    let modulus = modulus(&monkeys).unwrap();
+
+    for rounds in [0, 1, 20, 1000, 10_000] {
+        let mut played = monkeys.clone();
+        play_rounds(&mut played, rounds, |worry| worry % modulus).unwrap();
+        assert_eq!(
+            simulate(&monkeys, rounds as u64),
+            Ok(played.iter().map(|m| m.rounds).collect())
+        );
+    }
+
+    // every item is inspected at least once a round
+    let inspections = simulate(&monkeys, 1_000_000_000_000).unwrap();
+    assert!(inspections.iter().sum::<u64>() >= 10 * 1_000_000_000_000);
+
+    assert_eq!(simulate(&monkeys, u64::MAX), Err(too_many()));
+}
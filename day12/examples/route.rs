@@ -0,0 +1,47 @@
+//! Draw a shortest route up the hill.
+//!
+//! ```text
+//! cargo run -p day12 --example route -- <input> [--heatmap]
+//! ```
+//!
+//! Prints the route from the start with arrows, and with `--heatmap` the
+//! height map coloured by distance to the end, for terminals supporting
+//! 24-bit ANSI colours.
+
+use std::{env, fs, process::ExitCode};
+
+use day12::Map;
+
+fn main() -> ExitCode {
+    match draw(env::args().skip(1).collect()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn draw(args: Vec<String>) -> Result<(), String> {
+    let (input, heatmap) = match &args[..] {
+        [input] => (input, false),
+        [input, flag] if flag == "--heatmap" => (input, true),
+        _ => return Err("usage: route <input> [--heatmap]".to_owned()),
+    };
+
+    let text =
+        fs::read_to_string(input).map_err(|err| format!("cannot read {}: {}", input, err))?;
+    let map = text
+        .parse::<Map>()
+        .map_err(|err| format!("{}: {}", input, err))?;
+
+    let route = map
+        .route(map.start())
+        .ok_or("the end cannot be reached from the start")?;
+    println!("{}", map.render_route(&route));
+
+    if heatmap {
+        println!("\n{}", map.render_heatmap());
+    }
+    Ok(())
+}
@@ -0,0 +1,255 @@
+use std::{collections::VecDeque, str::FromStr};
+
+use aoc_core::{
+    geometry::Direction8,
+    grid::{Grid, Pos},
+    parse::{Mode, ParseError},
+    Solution, SolveError,
+};
+
+#[cfg(test)]
+use aoc_core::geometry::Direction;
+
+mod render;
+mod rules;
+
+pub use rules::{Cost, Rules};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str, _mode: Mode) -> Result<Map, ParseError> {
+        input.parse::<Map>()
+    }
+
+    fn part1(&self, map: &Map) -> Result<usize, SolveError> {
+        map.distances_to_end()[map.start]
+            .ok_or_else(|| SolveError::Invalid("no path found".to_owned()))
+    }
+
+    fn part2(&self, map: &Map) -> Result<usize, SolveError> {
+        let distances = map.distances_to_end();
+        map.heights
+            .iter()
+            .filter(|(_, &height)| height == 0)
+            .filter_map(|(pos, _)| distances[pos])
+            .min()
+            .ok_or_else(|| SolveError::Invalid("no path found".to_owned()))
+    }
+}
+
+#[test]
+fn test_problem1() {
+    assert_eq!(Day12.problem1(include_str!("testdata.txt")), Ok(31));
+}
+
+#[test]
+fn test_problem2() {
+    assert_eq!(Day12.problem2(include_str!("testdata.txt")), Ok(29));
+}
+
+#[derive(Debug)]
+pub struct Map {
+    start: Pos,
+    end: Pos,
+    heights: Grid<u8>,
+    rules: Rules,
+}
+
+impl Map {
+    /// Same map, where steps follow `rules` instead of the puzzle's
+    pub fn with_rules(self, rules: Rules) -> Self {
+        Self { rules, ..self }
+    }
+
+    pub fn start(&self) -> Pos {
+        self.start
+    }
+
+    pub fn end(&self) -> Pos {
+        self.end
+    }
+
+    pub fn rules(&self) -> Rules {
+        self.rules
+    }
+
+    /// Squares adjacent to `pos`, diagonally too if the rules allow it
+    fn neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .map(|d| d.delta::<isize>())
+            .filter(|delta| self.rules.diagonal || delta.x == 0 || delta.y == 0)
+            .filter_map(move |delta| self.heights.step(pos, delta))
+    }
+
+    /// Whether the rules allow a step from square `from` to the adjacent
+    /// square `to`
+    fn can_step(&self, from: Pos, to: Pos) -> bool {
+        self.rules.allows(self.heights[from], self.heights[to])
+    }
+
+    /// Squares reachable from `pos` in one step
+    fn successors(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbours(pos)
+            .filter(move |&next| self.can_step(pos, next))
+    }
+
+    /// Squares from which `pos` is reachable in one step
+    fn predecessors(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbours(pos)
+            .filter(move |&prev| self.can_step(prev, pos))
+    }
+
+    /// Fewest steps from every square to the end, `None` where it cannot be
+    /// reached.
+    ///
+    /// It is a single breadth-first search walking backwards from the end.
+    pub fn distances_to_end(&self) -> Grid<Option<usize>> {
+        let mut distances = self.heights.map(|_| None);
+        distances[self.end] = Some(0);
+
+        let mut queue = VecDeque::from([(self.end, 0)]);
+        while let Some((pos, distance)) = queue.pop_front() {
+            for prev in self.predecessors(pos) {
+                if distances[prev].is_none() {
+                    distances[prev] = Some(distance + 1);
+                    queue.push_back((prev, distance + 1));
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// Squares of a shortest route from `from` to the end, both included, or
+    /// `None` if `from` is outside the map or the end cannot be reached
+    pub fn route(&self, from: Pos) -> Option<Vec<Pos>> {
+        self.heights.get(from)?;
+        let distances = self.distances_to_end();
+        let mut route = vec![from];
+
+        let mut pos = from;
+        while let Some(distance @ 1..) = distances[pos] {
+            pos = self
+                .successors(pos)
+                .find(|&next| distances[next] == Some(distance - 1))?;
+            route.push(pos);
+        }
+
+        distances[pos].map(|_| route)
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let squares = Grid::parse(
+            s,
+            |c| matches!(c, 'a'..='z' | 'S' | 'E').then_some(c),
+            "a height between 'a' and 'z', 'S' or 'E'",
+        )?;
+        let find = |square: char| {
+            squares.find(|&c| c == square).ok_or_else(|| {
+                ParseError::new(s, &s[s.len()..], format!("a square marked '{}'", square))
+            })
+        };
+
+        Ok(Self {
+            start: find('S')?,
+            end: find('E')?,
+            heights: squares.map(|&c| match c {
+                'S' => char_to_num('a'),
+                'E' => char_to_num('z'),
+                c => char_to_num(c),
+            }),
+            rules: Rules::default(),
+        })
+    }
+}
+
+const fn char_to_num(c: char) -> u8 {
+    c as u8 - b'a'
+}
+
+#[test]
+fn test_distances_to_end() {
+    let map = include_str!("testdata.txt").parse::<Map>().unwrap();
+    let distances = map.distances_to_end();
+
+    assert_eq!(distances[map.end()], Some(0));
+    assert_eq!(distances[map.start()], Some(31));
+    // both neighbours of the start, in the top left corner, are on a
+    // shortest path
+    assert_eq!(distances[Pos::new(0, 1)], Some(30));
+    assert_eq!(distances[Pos::new(1, 0)], Some(30));
+
+    let cliff = "SzE".parse::<Map>().unwrap();
+    assert_eq!(
+        cliff.distances_to_end().cells(),
+        &[None, Some(1), Some(0)][..]
+    );
+}
+
+#[test]
+fn test_route() {
+    let map = include_str!("testdata.txt").parse::<Map>().unwrap();
+    let route = map.route(map.start()).unwrap();
+
+    assert_eq!(route.len(), 32);
+    assert_eq!(route.last(), Some(&map.end()));
+    assert!(route
+        .windows(2)
+        .all(|step| step[0].manhattan(step[1]) == 1 && map.can_step(step[0], step[1])));
+
+    assert_eq!(map.route(map.end()), Some(vec![map.end()]));
+    assert_eq!("SzE".parse::<Map>().unwrap().route(Pos::new(0, 0)), None);
+    assert_eq!(map.route(Pos::new(8, 0)), None);
+    assert_eq!(map.route(Pos::new(0, 5)), None);
+}
+
+/// Whether the map allows a step from `(x, y)` in `direction`
+#[cfg(test)]
+fn can_go(map: &Map, (x, y): (usize, usize), direction: Direction) -> bool {
+    let pos = Pos::new(x, y);
+    map.heights
+        .step(pos, direction.delta())
+        .is_some_and(|next| map.can_step(pos, next))
+}
+
+#[test]
+fn test_can_go_down() {
+    let map = include_str!("testdata.txt").parse::<Map>().unwrap();
+    assert!(can_go(&map, (0, 0), Direction::Down));
+    assert!(can_go(&map, (1, 1), Direction::Down));
+    assert!(!can_go(&map, (4, 0), Direction::Down));
+}
+
+#[test]
+fn test_can_go_up() {
+    let map = include_str!("testdata.txt").parse::<Map>().unwrap();
+    assert!(!can_go(&map, (0, 0), Direction::Up));
+    assert!(can_go(&map, (6, 3), Direction::Up));
+}
+
+#[test]
+fn test_can_go_right() {
+    let map = include_str!("testdata.txt").parse::<Map>().unwrap();
+    assert!(can_go(&map, (0, 0), Direction::Right));
+    assert!(can_go(&map, (5, 3), Direction::Right));
+    assert!(!can_go(&map, (7, 0), Direction::Right));
+}
+
+#[test]
+fn test_can_go_left() {
+    let map = include_str!("testdata.txt").parse::<Map>().unwrap();
+    assert!(!can_go(&map, (0, 0), Direction::Left));
+    assert!(can_go(&map, (6, 1), Direction::Left));
+}
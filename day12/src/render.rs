@@ -0,0 +1,116 @@
+use std::{cmp::Ordering, fmt::Write};
+
+use aoc_core::grid::{Grid, Pos};
+
+use crate::Map;
+
+impl Map {
+    /// Height map letter of every square, with the start `S` and the end `E`
+    fn letters(&self) -> Grid<char> {
+        let mut letters = self.heights.map(|&h| (b'a' + h) as char);
+        letters[self.start] = 'S';
+        letters[self.end] = 'E';
+        letters
+    }
+
+    /// `route` drawn as in the puzzle: every square of it shows an arrow
+    /// towards the next one, the end is `E` and the other squares are `.`.
+    /// Diagonal steps are drawn as `/` or `\`.
+    pub fn render_route(&self, route: &[Pos]) -> String {
+        let mut canvas = self.heights.map(|_| '.');
+        for step in route.windows(2) {
+            let (from, to) = (step[0], step[1]);
+            canvas[from] = match (to.x.cmp(&from.x), to.y.cmp(&from.y)) {
+                (Ordering::Greater, Ordering::Equal) => '>',
+                (Ordering::Less, Ordering::Equal) => '<',
+                (Ordering::Equal, Ordering::Greater) => 'v',
+                (Ordering::Equal, _) => '^',
+                (Ordering::Greater, Ordering::Less) | (Ordering::Less, Ordering::Greater) => '/',
+                _ => '\\',
+            };
+        }
+        canvas[self.end] = 'E';
+        canvas.to_string()
+    }
+
+    /// Height map with every square coloured by its distance to the end,
+    /// from green when close to red when far, using ANSI escape codes.
+    /// Squares that cannot reach the end are left uncoloured.
+    pub fn render_heatmap(&self) -> String {
+        let distances = self.distances_to_end();
+        let max = distances
+            .cells()
+            .iter()
+            .flatten()
+            .max()
+            .copied()
+            .unwrap_or(0);
+        let letters = self.letters();
+
+        let mut heatmap = String::new();
+        for (pos, &distance) in distances.iter() {
+            if pos.x == 0 && pos.y > 0 {
+                heatmap.push('\n');
+            }
+
+            let letter = letters[pos];
+            // Writing to a `String` cannot fail
+            let _ = match distance {
+                Some(distance) => {
+                    let (r, g, b) = gradient(distance, max);
+                    write!(
+                        heatmap,
+                        "\x1b[38;2;0;0;0;48;2;{};{};{}m{}\x1b[0m",
+                        r, g, b, letter
+                    )
+                }
+                None => write!(heatmap, "{}", letter),
+            };
+        }
+        heatmap
+    }
+}
+
+/// Colour of `value` between green for 0 and red for `max`, through yellow
+fn gradient(value: usize, max: usize) -> (u8, u8, u8) {
+    let t = if max == 0 {
+        0.0
+    } else {
+        value as f64 / max as f64
+    };
+    let red = (2.0 * t).min(1.0);
+    let green = (2.0 * (1.0 - t)).min(1.0);
+    ((red * 255.0) as u8, (green * 255.0) as u8, 64)
+}
+
+#[test]
+fn test_render_route() {
+    let map = include_str!("testdata.txt").parse::<Map>().unwrap();
+    let route = map.route(map.start()).unwrap();
+    let rendered = map.render_route(&route);
+
+    assert_eq!(rendered.lines().count(), 5);
+    assert_eq!(rendered.matches(['<', '>', '^', 'v']).count(), 31);
+    assert_eq!(rendered.lines().nth(2).unwrap().find('E'), Some(5));
+
+    let diagonal = [
+        Pos::new(0, 0),
+        Pos::new(1, 1),
+        Pos::new(2, 0),
+        Pos::new(3, 1),
+    ];
+    assert!(map
+        .render_route(&diagonal)
+        .starts_with("\\.\\.....\n./......"));
+}
+
+#[test]
+fn test_render_heatmap() {
+    // the start cannot climb to `y`, which is one step from the end
+    let map = "SyE".parse::<Map>().unwrap();
+    assert_eq!(
+        map.render_heatmap(),
+        "S\x1b[38;2;0;0;0;48;2;255;0;64my\x1b[0m\x1b[38;2;0;0;0;48;2;0;255;64mE\x1b[0m"
+    );
+    assert_eq!(gradient(1, 2), (255, 255, 64));
+}
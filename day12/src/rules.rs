@@ -0,0 +1,190 @@
+use aoc_core::grid::{Grid, Pos};
+use pathfinding::prelude::{astar, dijkstra_all};
+
+use crate::Map;
+
+/// Which steps between adjacent squares are allowed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    /// Most a step may climb
+    pub max_climb: u8,
+    /// Most a step may descend, `None` for no limit
+    pub max_descent: Option<u8>,
+    /// Whether steps may also be diagonal
+    pub diagonal: bool,
+}
+
+impl Rules {
+    /// Rules of the puzzle: climb at most one, descend any height, and only
+    /// step orthogonally
+    pub const PUZZLE: Rules = Rules {
+        max_climb: 1,
+        max_descent: None,
+        diagonal: false,
+    };
+
+    /// Whether a step from a square of height `from` to one of height `to`
+    /// is allowed
+    pub fn allows(self, from: u8, to: u8) -> bool {
+        if to >= from {
+            to - from <= self.max_climb
+        } else {
+            self.max_descent.is_none_or(|max| from - to <= max)
+        }
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self::PUZZLE
+    }
+}
+
+/// Cost of a step: `step`, plus `climb` for every height gained and
+/// `descent` for every height lost
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cost {
+    pub step: u64,
+    pub climb: u64,
+    pub descent: u64,
+}
+
+impl Cost {
+    /// Every step costs 1, whatever the heights
+    pub const STEPS: Cost = Cost {
+        step: 1,
+        climb: 0,
+        descent: 0,
+    };
+
+    /// Cost of a step from a square of height `from` to one of height `to`
+    pub fn of(self, from: u8, to: u8) -> u64 {
+        let change = from.abs_diff(to) as u64;
+        self.step
+            + if to > from {
+                self.climb * change
+            } else {
+                self.descent * change
+            }
+    }
+}
+
+impl Default for Cost {
+    fn default() -> Self {
+        Self::STEPS
+    }
+}
+
+impl Map {
+    fn step_cost(&self, cost: Cost, from: Pos, to: Pos) -> u64 {
+        cost.of(self.heights[from], self.heights[to])
+    }
+
+    /// Cheapest route from `from` to the end with its cost, or `None` if
+    /// `from` is outside the map or the end cannot be reached.
+    ///
+    /// It is an A* search, estimating the cost left from the fewest steps
+    /// and the height still to climb or descend.
+    pub fn cheapest_route(&self, from: Pos, cost: Cost) -> Option<(Vec<Pos>, u64)> {
+        self.heights.get(from)?;
+        let end_height = self.heights[self.end];
+        let heuristic = |&pos: &Pos| {
+            let steps = if self.rules.diagonal {
+                pos.chebyshev(self.end)
+            } else {
+                pos.manhattan(self.end)
+            };
+            let height = self.heights[pos];
+            cost.step * steps as u64
+                + cost.climb * end_height.saturating_sub(height) as u64
+                + cost.descent * height.saturating_sub(end_height) as u64
+        };
+
+        astar(
+            &from,
+            |&pos| {
+                self.successors(pos)
+                    .map(move |next| (next, self.step_cost(cost, pos, next)))
+            },
+            heuristic,
+            |&pos| pos == self.end,
+        )
+    }
+
+    /// Cost of the cheapest route from every square to the end, `None`
+    /// where it cannot be reached.
+    ///
+    /// It is a single Dijkstra search walking backwards from the end.
+    pub fn costs_to_end(&self, cost: Cost) -> Grid<Option<u64>> {
+        let parents = dijkstra_all(&self.end, |&pos| {
+            self.predecessors(pos)
+                .map(move |prev| (prev, self.step_cost(cost, prev, pos)))
+        });
+
+        let mut costs = self.heights.map(|_| None);
+        costs[self.end] = Some(0);
+        for (pos, (_, total)) in parents {
+            costs[pos] = Some(total);
+        }
+        costs
+    }
+}
+
+#[test]
+fn test_rules() {
+    assert!(Rules::PUZZLE.allows(3, 4));
+    assert!(Rules::PUZZLE.allows(25, 0));
+    assert!(!Rules::PUZZLE.allows(3, 5));
+
+    let flat = Rules {
+        max_climb: 2,
+        max_descent: Some(1),
+        diagonal: false,
+    };
+    assert!(flat.allows(3, 5));
+    assert!(flat.allows(3, 2));
+    assert!(!flat.allows(3, 1));
+}
+
+#[test]
+fn test_diagonal() {
+    let map = include_str!("testdata.txt").parse::<Map>().unwrap();
+    let map = map.with_rules(Rules {
+        diagonal: true,
+        ..Rules::PUZZLE
+    });
+
+    let route = map.route(map.start()).unwrap();
+    assert!(route.len() < 32);
+    assert!(route.windows(2).all(|step| step[0].chebyshev(step[1]) == 1));
+    assert_eq!(map.distances_to_end()[map.start()], Some(route.len() - 1));
+}
+
+#[test]
+fn test_weighted() {
+    let map = include_str!("testdata.txt").parse::<Map>().unwrap();
+
+    let (route, steps) = map.cheapest_route(map.start(), Cost::STEPS).unwrap();
+    assert_eq!((route.len(), steps), (32, 31));
+
+    let climbing = Cost {
+        step: 1,
+        climb: 3,
+        descent: 10,
+    };
+    let (route, total) = map.cheapest_route(map.start(), climbing).unwrap();
+    assert_eq!(map.costs_to_end(climbing)[map.start()], Some(total));
+    assert_eq!(
+        route
+            .windows(2)
+            .map(|step| map.step_cost(climbing, step[0], step[1]))
+            .sum::<u64>(),
+        total
+    );
+    // climbing from `a` to `z` costs at least 3 for each height
+    assert!(total >= 31 + 3 * 25);
+
+    let cliff = "SzE".parse::<Map>().unwrap();
+    assert_eq!(cliff.cheapest_route(Pos::new(0, 0), Cost::STEPS), None);
+    assert_eq!(map.cheapest_route(Pos::new(8, 0), Cost::STEPS), None);
+}
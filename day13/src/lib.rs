@@ -0,0 +1,202 @@
+use std::{cmp::Ordering, fmt::Debug, str::FromStr, vec};
+
+use aoc_core::{
+    parse::{self, Mode, ParseError},
+    Solution, SolveError,
+};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<(Node, Node)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str, mode: Mode) -> Result<Self::Input, ParseError> {
+        parse_node_pairs(input, mode)
+    }
+
+    fn part1(&self, pairs: &Self::Input) -> Result<usize, SolveError> {
+        Ok(pairs
+            .iter()
+            .enumerate()
+            .filter(|(_, (left, right))| left <= right)
+            .map(|(i, _)| i + 1)
+            .sum())
+    }
+
+    fn part2(&self, pairs: &Self::Input) -> Result<usize, SolveError> {
+        let mut nodes = pairs
+            .iter()
+            .flat_map(|(left, right)| [left.clone(), right.clone()])
+            .collect::<Vec<_>>();
+
+        let divider2: Node = "[[2]]".parse()?;
+        nodes.push(divider2.clone());
+        let divider6: Node = "[[6]]".parse()?;
+        nodes.push(divider6.clone());
+
+        nodes.sort();
+        let divider2_idx = 1 + nodes.binary_search(&divider2).unwrap_or_else(|i| i);
+        let divider6_idx = 1 + nodes.binary_search(&divider6).unwrap_or_else(|i| i);
+        Ok(divider2_idx * divider6_idx)
+    }
+}
+
+#[test]
+fn test_problem1() {
+    assert_eq!(Day13.problem1(include_str!("testdata.txt")), Ok(13));
+}
+
+#[test]
+fn test_problem2() {
+    assert_eq!(Day13.problem2(include_str!("testdata.txt")), Ok(140));
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Node {
+    Simple(u8),
+    Complex(Vec<Node>),
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Node::Simple(left), Node::Simple(right)) => left.cmp(right),
+            (Node::Simple(_), Node::Complex(_)) => Self::Complex(vec![self.clone()]).cmp(other),
+            (Node::Complex(_), Node::Simple(_)) => self.cmp(&Self::Complex(vec![other.clone()])),
+            (Node::Complex(left), Node::Complex(right)) => {
+                for i in 0..usize::min(left.len(), right.len()) {
+                    let cmp = left[i].cmp(&right[i]);
+                    if cmp.is_ne() {
+                        return cmp;
+                    }
+                }
+
+                left.len().cmp(&right.len())
+            }
+        }
+    }
+}
+
+impl FromStr for Node {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // lists still open, with the position of their '['
+        let mut stack: Vec<(usize, Vec<Node>)> = Vec::default();
+        let mut packet = None;
+        let mut chars = s.char_indices().peekable();
+
+        while let Some((i, c)) = chars.next() {
+            let token = &s[i..i + c.len_utf8()];
+            if packet.is_some() && c != ' ' {
+                return Err(ParseError::new(s, token, "the end of the packet"));
+            }
+
+            match c {
+                '0'..='9' => {
+                    let mut end = i + 1;
+                    while let Some(&(j, '0'..='9')) = chars.peek() {
+                        end = j + 1;
+                        chars.next();
+                    }
+
+                    let number = &s[i..end];
+                    let a = parse::token(s, number, "a number between 0 and 255")?;
+                    match stack.last_mut() {
+                        Some((_, v)) => v.push(Self::Simple(a)),
+                        None => return Err(ParseError::new(s, number, "a list starting with '['")),
+                    }
+                }
+                '[' => stack.push((i, Vec::default())),
+                ']' => {
+                    let Some((_, v)) = stack.pop() else {
+                        return Err(ParseError::new(s, token, "a '[' before this ']'"));
+                    };
+                    match stack.last_mut() {
+                        Some((_, parent)) => parent.push(Self::Complex(v)),
+                        None => packet = Some(Self::Complex(v)),
+                    }
+                }
+                ',' | ' ' => {}
+                _ => return Err(ParseError::new(s, token, "a number, ',', '[' or ']'")),
+            }
+        }
+
+        if let Some(&(i, _)) = stack.last() {
+            return Err(ParseError::new(s, &s[i..i + 1], "a list closed by ']'"));
+        }
+        packet.ok_or_else(|| ParseError::new(s, s, "a list starting with '['"))
+    }
+}
+
+fn parse_node_pairs(s: &str, mode: Mode) -> Result<Vec<(Node, Node)>, ParseError> {
+    parse::items(s, s.split("\n\n"), mode, |pair| {
+        let Some((left, right)) = pair.trim_end().split_once('\n') else {
+            return Err(ParseError::new(
+                pair,
+                pair,
+                "two packets on consecutive lines",
+            ));
+        };
+
+        Ok((
+            left.parse::<Node>().map_err(|e| e.within(pair, left))?,
+            right.parse::<Node>().map_err(|e| e.within(pair, right))?,
+        ))
+    })
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(
+        "[1,[2,[]]]".parse::<Node>(),
+        Ok(Node::Complex(vec![
+            Node::Simple(1),
+            Node::Complex(vec![Node::Simple(2), Node::Complex(vec![])]),
+        ]))
+    );
+
+    let error = |s: &str| {
+        let err = s.parse::<Node>().unwrap_err();
+        (err.column, err.text, err.expected)
+    };
+    assert_eq!(
+        error("1]"),
+        (1, "1".to_owned(), "a list starting with '['".to_owned())
+    );
+    assert_eq!(
+        error("5"),
+        (1, "5".to_owned(), "a list starting with '['".to_owned())
+    );
+    assert_eq!(
+        error("[1]]"),
+        (4, "]".to_owned(), "the end of the packet".to_owned())
+    );
+    assert_eq!(
+        error("[1],[2]"),
+        (4, ",".to_owned(), "the end of the packet".to_owned())
+    );
+    assert_eq!(
+        error("[[1]"),
+        (1, "[".to_owned(), "a list closed by ']'".to_owned())
+    );
+    assert_eq!(
+        error("[1,2"),
+        (1, "[".to_owned(), "a list closed by ']'".to_owned())
+    );
+    assert_eq!(
+        error("]"),
+        (1, "]".to_owned(), "a '[' before this ']'".to_owned())
+    );
+    assert!("".parse::<Node>().is_err());
+}
@@ -0,0 +1,249 @@
+use std::{fmt::Display, str::FromStr};
+
+use aoc_core::{
+    geometry::Direction8,
+    grid::{Grid, Pos},
+    parse::{self, Mode, ParseError},
+    Solution, SolveError,
+};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Cave;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str, mode: Mode) -> Result<Cave, ParseError> {
+        Cave::parse(input, mode)
+    }
+
+    fn part1(&self, cave: &Cave) -> Result<usize, SolveError> {
+        let mut cave = cave.clone();
+        let max_rock_y = cave.max_rock_y()?;
+
+        // sand flows into the abyss below the lowest rock, unless it piles
+        // up to the source first
+        loop {
+            let was_source = cave.current == cave.source();
+            cave.step(None);
+            if cave.current.y == max_rock_y || was_source && cave.current == cave.source() {
+                return Ok(cave.sand());
+            }
+        }
+    }
+
+    fn part2(&self, cave: &Cave) -> Result<usize, SolveError> {
+        let mut cave = cave.clone();
+        let max_rock_y = cave.max_rock_y()?;
+        loop {
+            let was_source = cave.current == cave.source();
+            cave.step(Some(max_rock_y + 2));
+            if was_source && cave.current == cave.source() {
+                return Ok(cave.sand());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_problem1() {
+    assert_eq!(Day14.problem1(include_str!("testdata.txt")), Ok(24));
+}
+
+#[test]
+fn test_problem2() {
+    assert_eq!(Day14.problem2(include_str!("testdata.txt")), Ok(93));
+}
+
+#[test]
+fn test_problem1_filled_to_source() {
+    // the first unit rests on the rock, right at the source
+    assert_eq!(Day14.problem1("499,1 -> 501,1"), Ok(1));
+}
+
+#[test]
+fn test_display() {
+    let cave = include_str!("testdata.txt").parse::<Cave>().unwrap();
+    let rows = cave.to_string();
+
+    assert_eq!(
+        rows.lines().skip(4).take(6).collect::<Vec<_>>(),
+        vec![
+            ".........#...##........",
+            ".........#...#.........",
+            ".......###...#.........",
+            ".............#.........",
+            ".............#.........",
+            ".....#########.........",
+        ]
+    );
+    assert_eq!(rows.lines().count(), 12);
+}
+
+/// Where the sand comes from
+const SOURCE: Pos = Pos::new(500, 0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tile::Air => write!(f, "."),
+            Tile::Rock => write!(f, "#"),
+            Tile::Sand => write!(f, "o"),
+        }
+    }
+}
+
+/// Slice of the cave, wide enough for the sand to pile up on the floor
+#[derive(Debug, Clone)]
+pub struct Cave {
+    tiles: Grid<Tile>,
+    /// Column of the cave at the left edge of `tiles`
+    left: usize,
+    /// Depth of the lowest rock, if there is any
+    lowest: Option<usize>,
+    /// Position of the falling unit of sand, in `tiles`
+    current: Pos,
+}
+
+impl Cave {
+    /// Parse the rock paths of `s`, one per line
+    fn parse(s: &str, mode: Mode) -> Result<Self, ParseError> {
+        let rocks = parse::items(s, s.lines(), mode, rock_path)?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+
+        Ok(Self::new(&rocks))
+    }
+
+    fn new(rocks: &[Pos]) -> Self {
+        let lowest = rocks.iter().map(|rock| rock.y).max();
+        let floor = lowest.unwrap_or(0) + 2;
+
+        // The sand piled on the floor spreads at most `floor` columns away
+        // from the source
+        let left = rocks
+            .iter()
+            .map(|rock| rock.x)
+            .chain([SOURCE.x.saturating_sub(floor)])
+            .min()
+            .unwrap_or_default();
+        let right = rocks
+            .iter()
+            .map(|rock| rock.x)
+            .chain([SOURCE.x + floor])
+            .max()
+            .unwrap_or_default();
+
+        let mut tiles = Grid::filled(right - left + 1, floor + 1, Tile::Air);
+        for rock in rocks {
+            tiles[Pos::new(rock.x - left, rock.y)] = Tile::Rock;
+        }
+
+        Self {
+            tiles,
+            left,
+            lowest,
+            current: Pos::new(SOURCE.x - left, SOURCE.y),
+        }
+    }
+
+    /// Depth of the lowest rock
+    fn max_rock_y(&self) -> Result<usize, SolveError> {
+        self.lowest
+            .ok_or_else(|| SolveError::Invalid("the cave has no rocks".to_owned()))
+    }
+
+    fn source(&self) -> Pos {
+        Pos::new(SOURCE.x - self.left, SOURCE.y)
+    }
+
+    fn sand(&self) -> usize {
+        self.tiles
+            .cells()
+            .iter()
+            .filter(|&&t| t == Tile::Sand)
+            .count()
+    }
+
+    /// Move the falling unit of sand one step down, or let it rest and pour
+    /// the next one if it cannot move, or if it reached depth `floor - 1`
+    fn step(&mut self, floor: Option<usize>) {
+        let next = if floor == Some(self.current.y + 1) {
+            None
+        } else {
+            [
+                Direction8::Down,
+                Direction8::DownLeft,
+                Direction8::DownRight,
+            ]
+            .into_iter()
+            .flat_map(|d| self.tiles.step(self.current, d.delta()))
+            .find(|&pos| self.tiles[pos] == Tile::Air)
+        };
+
+        match next {
+            Some(pos) => self.current = pos,
+            None => {
+                self.tiles[self.current] = Tile::Sand;
+                self.current = self.source();
+            }
+        }
+    }
+}
+
+impl Display for Cave {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.tiles)
+    }
+}
+
+impl FromStr for Cave {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Cave::parse(s, Mode::Strict)
+    }
+}
+
+/// Positions of the rocks along the path described by line `l`
+fn rock_path(l: &str) -> Result<Vec<Pos>, ParseError> {
+    let edges = l
+        .split(" -> ")
+        .map(|s| Ok((s, s.parse::<Pos>().map_err(|e| e.within(l, s))?)))
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    let mut rocks = vec![];
+    for chunks in edges.windows(2) {
+        match chunks {
+            [(_, a), (_, b)] if a.x == b.x => {
+                let (min, max) = (a.y.min(b.y), a.y.max(b.y));
+                rocks.extend((min..=max).map(|y| Pos::new(a.x, y)));
+            }
+            [(_, a), (_, b)] if a.y == b.y => {
+                let (min, max) = (a.x.min(b.x), a.x.max(b.x));
+                rocks.extend((min..=max).map(|x| Pos::new(x, a.y)));
+            }
+            [_, (s, _)] => {
+                return Err(ParseError::new(
+                    l,
+                    s,
+                    "a point on the same row or column as the previous one",
+                ))
+            }
+            _ => unreachable!("windows(2) yields pairs"),
+        }
+    }
+
+    Ok(rocks)
+}
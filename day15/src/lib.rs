@@ -0,0 +1,137 @@
+use std::{collections::HashSet, str::FromStr};
+
+use aoc_core::{
+    geometry::Point,
+    parse::{self, Mode, ParseError},
+    Solution, SolveError,
+};
+
+type Pos = Point<i32>;
+
+pub struct Day15 {
+    /// Row scanned for positions where a beacon cannot be
+    row: i32,
+}
+
+impl Default for Day15 {
+    fn default() -> Self {
+        Self { row: 2_000_000 }
+    }
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Vec<Measure>;
+    type Answer1 = usize;
+    type Answer2 = u32;
+
+    fn parse(&self, input: &str, mode: Mode) -> Result<Vec<Measure>, ParseError> {
+        parse::lines(input, mode)
+    }
+
+    fn part1(&self, measures: &Vec<Measure>) -> Result<usize, SolveError> {
+        let mut visited = visited_pos(measures.as_slice(), self.row);
+        measures
+            .iter()
+            .flat_map(|m| [&m.closest, &m.sensor])
+            .for_each(|pos| {
+                visited.remove(pos);
+            });
+
+        Ok(visited.len())
+    }
+
+    fn part2(&self, _measures: &Vec<Measure>) -> Result<u32, SolveError> {
+        Ok(0)
+    }
+}
+
+fn visited_pos(measures: &[Measure], y: i32) -> HashSet<Pos> {
+    measures
+        .iter()
+        .filter(|m| (m.sensor.y.abs_diff(y) as usize) <= m.dist())
+        .flat_map(|m| {
+            let dist_y = (m.dist() - m.sensor.y.abs_diff(y) as usize) as i32;
+            (0..=dist_y).flat_map(|d| [Pos::new(m.sensor.x + d, y), Pos::new(m.sensor.x - d, y)])
+        })
+        .collect::<HashSet<_>>()
+}
+
+#[test]
+fn test_problem1() {
+    let day = Day15 { row: 10 };
+    assert_eq!(day.problem1(include_str!("testdata.txt")), Ok(26));
+}
+
+#[test]
+fn test_problem2() {
+    let day = Day15 { row: 10 };
+    assert_eq!(day.problem2(include_str!("testdata.txt")), Ok(0));
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Measure {
+    sensor: Pos,
+    closest: Pos,
+}
+impl Measure {
+    fn dist(&self) -> usize {
+        self.sensor.manhattan(self.closest) as usize
+    }
+}
+
+impl FromStr for Measure {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (Some(sensor), Some(closest)) = (
+            s.strip_prefix("Sensor at "),
+            s.split_once(": closest beacon is at ").map(|(_, b)| b),
+        ) else {
+            return Err(ParseError::new(
+                s,
+                s,
+                "'Sensor at x=<x>, y=<y>: closest beacon is at x=<x>, y=<y>'",
+            ));
+        };
+        let sensor = &sensor[..sensor.find(':').unwrap_or(sensor.len())];
+
+        Ok(Self {
+            sensor: sensor
+                .parse()
+                .map_err(|e: ParseError| e.within(s, sensor))?,
+            closest: closest
+                .parse()
+                .map_err(|e: ParseError| e.within(s, closest))?,
+        })
+    }
+}
+
+#[test]
+fn test_dist() {
+    let measure = Measure {
+        sensor: Pos::new(8, 7),
+        closest: Pos::new(2, 10),
+    };
+    assert_eq!(measure.dist(), 9);
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(
+        "Sensor at x=3482210, y=422224: closest beacon is at x=2273934, y=-202439".parse(),
+        Ok(Measure {
+            sensor: Pos::new(3482210, 422224),
+            closest: Pos::new(2273934, -202439)
+        })
+    );
+}
+
+#[test]
+fn test_parse_error() {
+    let err = "Sensor at x=3, y=a: closest beacon is at x=2, y=-2"
+        .parse::<Measure>()
+        .unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (1, 18, "a"));
+}
@@ -0,0 +1,36 @@
+use aoc_core::{
+    parse::{Mode, ParseError},
+    Solution, SolveError,
+};
+
+pub struct Day00;
+
+impl Solution for Day00 {
+    const DAY: u8 = 0;
+
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, input: &str, _mode: Mode) -> Result<Vec<String>, ParseError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(&self, _input: &Vec<String>) -> Result<u32, SolveError> {
+        Ok(0)
+    }
+
+    fn part2(&self, _input: &Vec<String>) -> Result<u32, SolveError> {
+        Ok(0)
+    }
+}
+
+#[test]
+fn test_problem1() {
+    assert_eq!(Day00.problem1(include_str!("testdata.txt")), Ok(0));
+}
+
+#[test]
+fn test_problem2() {
+    assert_eq!(Day00.problem2(include_str!("testdata.txt")), Ok(0));
+}
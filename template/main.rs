@@ -1,24 +1,10 @@
-fn main() {
-    let input = include_str!("input.txt");
+use std::process::ExitCode;
 
-    println!("Problem 1: {}", problem1(input));
-    println!("Problem 2: {}", problem2(input));
-}
-
-fn problem1(input: &str) -> u32 {
-    0
-}
+#[cfg(feature = "embed")]
+const EMBEDDED: Option<&str> = Some(include_str!("../../inputs/day00.txt"));
+#[cfg(not(feature = "embed"))]
+const EMBEDDED: Option<&str> = None;
 
-fn problem2(input: &str) -> u32 {
-    0
+fn main() -> ExitCode {
+    aoc_core::main(&day00::Day00, EMBEDDED)
 }
-
-#[test]
-fn test_problem1() {
-    assert_eq!(problem1(include_str!("testdata.txt")), 0);
-}
-
-#[test]
-fn test_problem2() {
-    assert_eq!(problem2(include_str!("testdata.txt")), 0);
-}
\ No newline at end of file